@@ -0,0 +1,180 @@
+//! 构建脚本
+//!
+//! 读取与 `scripts/env-loader.js` 相同的 `.env*` 文件，校验 `VITE_*` 配置，
+//! 并在 `OUT_DIR` 中生成 `config.rs`（由 `src/config.rs` 引入）。
+//! 配置缺失或格式错误时直接让编译失败，而不是悄悄回退到默认值。
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 与运行时共用的解析函数
+#[path = "src/parsing.rs"]
+mod parsing;
+
+// 配置校验，单元测试位于该文件中
+#[path = "src/config_check.rs"]
+mod config_check;
+
+use config_check::{known_keys, parse_env_file, validate, ENVIRONMENTS, ROUTER_MODES};
+use parsing::parse_flags;
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    for key in known_keys() {
+        println!("cargo:rerun-if-env-changed={key}");
+    }
+
+    let mut errors = Vec::new();
+
+    // 与 env-loader.js 一致：先确定环境，再按 .env < .env.<环境> < 进程环境变量 的优先级合并
+    let mode = env::var("VITE_ENV").unwrap_or_else(|_| "development".to_string());
+    // 未知环境在 validate 中统一报错
+    let env_file = ENVIRONMENTS.iter().find(|(name, ..)| *name == mode).map(|(_, file, _)| *file);
+
+    let mut values = BTreeMap::new();
+    for file in [Some(".env"), env_file].into_iter().flatten() {
+        let path = root.join(file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        load_env_file(&path, &mut values, &mut errors);
+    }
+    for key in known_keys() {
+        if let Ok(value) = env::var(key) {
+            values.insert(key.to_string(), value);
+        }
+    }
+
+    validate(&values, &mut errors);
+
+    if !errors.is_empty() {
+        let mut message = String::from("invalid VITE_* configuration:\n");
+        for error in &errors {
+            let _ = writeln!(message, "  - {error}");
+        }
+        panic!("{message}");
+    }
+
+    let build = BuildMetadata::capture(&root);
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("config.rs"), generate(&values, &build))
+        .expect("failed to write config.rs");
+}
+
+/// 构建元数据：git 提交与构建时间
+struct BuildMetadata {
+    git_sha: Option<String>,
+    git_dirty: bool,
+    timestamp: u64,
+}
+
+impl BuildMetadata {
+    /// 不在 git 仓库中（例如从 crate 包构建）时 `git_sha` 为 `None`
+    fn capture(root: &Path) -> Self {
+        println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+        // 提交或切换分支时重新运行，index 变化用于更新 dirty 标记
+        let git_dir = git(root, &["rev-parse", "--git-dir"]).map(|dir| root.join(dir));
+        if let Some(git_dir) = &git_dir {
+            for file in ["HEAD", "index"] {
+                println!("cargo:rerun-if-changed={}", git_dir.join(file).display());
+            }
+            if let Some(head_ref) = git(root, &["symbolic-ref", "-q", "HEAD"]) {
+                println!("cargo:rerun-if-changed={}", git_dir.join(head_ref).display());
+            }
+        }
+
+        // 遵循 SOURCE_DATE_EPOCH 以支持可复现构建
+        let timestamp = env::var("SOURCE_DATE_EPOCH")
+            .ok()
+            .and_then(|epoch| epoch.parse().ok())
+            .unwrap_or_else(|| {
+                SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+            });
+
+        Self {
+            git_sha: git_dir.and_then(|_| git(root, &["rev-parse", "HEAD"])),
+            git_dirty: git(root, &["status", "--porcelain"]).is_some_and(|s| !s.is_empty()),
+            timestamp,
+        }
+    }
+}
+
+/// 运行 git 命令并返回去掉首尾空白的输出，失败时返回 `None`
+fn git(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).current_dir(root).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// 读取单个 `.env` 文件，文件不存在时跳过
+fn load_env_file(path: &Path, values: &mut BTreeMap<String, String>, errors: &mut Vec<String>) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return;
+    };
+    let file = path.file_name().unwrap().to_string_lossy();
+    parse_env_file(&file, &content, values, errors);
+}
+
+/// 生成 `config` 模块的源码
+fn generate(values: &BTreeMap<String, String>, build: &BuildMetadata) -> String {
+    let variant = ENVIRONMENTS
+        .iter()
+        .find(|(name, ..)| *name == values["VITE_ENV"])
+        .map(|(.., variant)| *variant)
+        .unwrap();
+
+    let mut out = String::from("// @generated by build.rs - do not edit\n\n");
+    let _ = writeln!(out, "/// 应用基础路径（`VITE_BASE_URL`）");
+    let _ = writeln!(out, "pub const BASE_URL: &str = {:?};\n", values["VITE_BASE_URL"]);
+    let _ = writeln!(out, "/// 应用标题（`VITE_APP_TITLE`）");
+    let _ = writeln!(out, "pub const APP_TITLE: &str = {:?};\n", values["VITE_APP_TITLE"]);
+    let _ = writeln!(out, "/// 部署环境（`VITE_ENV`）");
+    let _ = writeln!(
+        out,
+        "pub const ENV: crate::env::AppEnvironment = crate::env::AppEnvironment::{variant};\n"
+    );
+
+    let flags = values.get("VITE_FEATURE_FLAGS").map(String::as_str).unwrap_or_default();
+    let _ = writeln!(out, "/// 功能开关默认值（`VITE_FEATURE_FLAGS`）");
+    let _ = writeln!(out, "pub const FEATURE_FLAGS: &[(&str, bool)] = &[");
+    for (name, enabled) in parse_flags(flags) {
+        let _ = writeln!(out, "    ({name:?}, {enabled}),");
+    }
+    let _ = writeln!(out, "];\n");
+
+    let _ = writeln!(out, "/// 日志过滤规则（`VITE_LOG_FILTER`），未设置时使用环境默认级别");
+    let _ = writeln!(out, "pub const LOG_FILTER: Option<&str> = {:?};\n", values.get("VITE_LOG_FILTER"));
+
+    let router_mode = values
+        .get("VITE_ROUTER_MODE")
+        .and_then(|mode| ROUTER_MODES.iter().find(|(name, _)| name == mode))
+        .map_or("History", |(_, variant)| *variant);
+    let _ = writeln!(out, "/// 路由模式（`VITE_ROUTER_MODE`），未设置时使用 history 模式");
+    let _ = writeln!(
+        out,
+        "pub const ROUTER_MODE: crate::env::RouterMode = crate::env::RouterMode::{router_mode};\n"
+    );
+
+    let _ = writeln!(out, "/// 错误上报地址（`VITE_ERROR_ENDPOINT`），未设置时不上报");
+    let _ = writeln!(
+        out,
+        "pub const ERROR_ENDPOINT: Option<&str> = {:?};\n",
+        values.get("VITE_ERROR_ENDPOINT")
+    );
+
+    let _ = writeln!(out, "/// 构建时的 git 提交，不在 git 仓库中构建时为 `None`");
+    let _ = writeln!(out, "pub const GIT_SHA: Option<&str> = {:?};\n", build.git_sha);
+    let _ = writeln!(out, "/// 构建时工作区是否有未提交的修改");
+    let _ = writeln!(out, "pub const GIT_DIRTY: bool = {};\n", build.git_dirty);
+    let _ = writeln!(out, "/// 构建时间（Unix 秒）");
+    let _ = writeln!(out, "pub const BUILD_TIMESTAMP: u64 = {};", build.timestamp);
+    out
+}
@@ -9,6 +9,8 @@ import { execSync } from 'child_process';
 import fs from 'fs';
 import path from 'path';
 import { autoDetectEnvironment, loadAndSetEnv, displayEnvInfo } from './env-loader.js';
+import { splitBuildCommands } from './split-wasm.js';
+import { writeSpaFallback } from './spa-fallback.js';
 
 /**
  * 清理旧的构建产物
@@ -34,8 +36,9 @@ function cleanupOldBuilds() {
  * 执行构建步骤
  * @param {string} environment - 环境类型
  * @param {boolean} release - 是否为 release 构建
+ * @param {boolean} split - 是否将懒加载页面拆分为独立的 wasm 模块
  */
-async function buildAll(environment, release = false) {
+async function buildAll(environment, release = false, split = false) {
   console.log('🚀 Starting complete build process...');
   console.log(`Environment: ${environment}, Release: ${release}`);
   
@@ -52,13 +55,17 @@ async function buildAll(environment, release = false) {
     
     // 步骤 1: 构建 WASM
     console.log('📦 Step 1: Building WASM...');
-    const wasmCommand = `wasm-pack build --target web --no-typescript ${profile}`;
-    console.log(`   Command: ${wasmCommand}`);
+    const wasmCommands = split
+      ? splitBuildCommands(release)
+      : [`wasm-pack build --target web ${profile}`];
     
-    execSync(wasmCommand, {
-      stdio: 'inherit',
-      env: process.env
-    });
+    for (const wasmCommand of wasmCommands) {
+      console.log(`   Command: ${wasmCommand}`);
+      execSync(wasmCommand, {
+        stdio: 'inherit',
+        env: process.env
+      });
+    }
     
     console.log('   ✅ WASM build completed');
     
@@ -74,6 +81,12 @@ async function buildAll(environment, release = false) {
     
     console.log('   ✅ Vite build completed');
     
+    // 步骤 3: GitHub Pages 需要 404.html 把深层链接重定向回应用
+    if (environment === 'github-pages') {
+      console.log('🔀 Step 3: Generating SPA fallback page...');
+      writeSpaFallback('dist', process.env.VITE_BASE_URL);
+    }
+    
     // 显示构建结果
     await displayBuildResults();
     
@@ -133,7 +146,10 @@ function main() {
   // 检查是否为 release 构建
   const isRelease = args.includes('--release') || args.includes('-r');
   
-  buildAll(environment, isRelease);
+  // 检查是否拆分懒加载页面
+  const isSplit = args.includes('--split');
+  
+  buildAll(environment, isRelease, isSplit);
 }
 
 // 执行主函数
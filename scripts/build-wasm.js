@@ -11,13 +11,15 @@ import {
   displayEnvInfo,
   loadAndSetEnv,
 } from "./env-loader.js";
+import { splitBuildCommands } from "./split-wasm.js";
 
 /**
  * 构建 WASM
  * @param {string} environment - 环境类型
  * @param {boolean} release - 是否为 release 构建
+ * @param {boolean} split - 是否将懒加载页面拆分为独立的 wasm 模块
  */
-function buildWasm(environment, release = false) {
+function buildWasm(environment, release = false, split = false) {
   console.log("🦀 Starting WASM build process...\n");
 
   // 加载环境变量
@@ -29,15 +31,18 @@ function buildWasm(environment, release = false) {
 
   // 构建命令
   const profile = release ? "--release" : "--dev";
-  const command = `wasm-pack build --target web --no-typescript ${profile}`;
-
-  console.log(`🔨 Executing: ${command}`);
+  const commands = split
+    ? splitBuildCommands(release)
+    : [`wasm-pack build --target web ${profile}`];
 
   try {
-    execSync(command, {
-      stdio: "inherit",
-      env: process.env,
-    });
+    for (const command of commands) {
+      console.log(`🔨 Executing: ${command}`);
+      execSync(command, {
+        stdio: "inherit",
+        env: process.env,
+      });
+    }
 
     console.log("\n✅ WASM build completed successfully!");
 
@@ -68,7 +73,10 @@ function main() {
   // 检查是否为 release 构建
   const isRelease = args.includes("--release") || args.includes("-r");
 
-  buildWasm(environment, isRelease);
+  // 检查是否拆分懒加载页面
+  const isSplit = args.includes("--split");
+
+  buildWasm(environment, isRelease, isSplit);
 }
 
 // 执行主函数
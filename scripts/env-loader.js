@@ -152,7 +152,7 @@ export function autoDetectEnvironment() {
  */
 export function displayEnvInfo(env) {
   console.log('📋 Environment Variables:');
-  const keyVars = ['VITE_BASE_URL', 'VITE_ENV', 'VITE_APP_TITLE'];
+  const keyVars = ['VITE_BASE_URL', 'VITE_ENV', 'VITE_APP_TITLE', 'VITE_FEATURE_FLAGS', 'VITE_LOG_FILTER', 'VITE_ROUTER_MODE', 'VITE_ERROR_ENDPOINT'];
   
   keyVars.forEach(key => {
     const value = env[key] || process.env[key] || 'undefined';
@@ -0,0 +1,76 @@
+#!/usr/bin/env node
+
+/**
+ * 本地错误接收端
+ * 代替真实的错误收集服务，把应用上报的错误打印到终端，便于开发时验证上报流程。
+ *
+ * 使用: pnpm errors:receive [--port=4318] [--fail-rate=0.5]
+ * 然后设置 VITE_ERROR_ENDPOINT=http://localhost:4318/errors（或在 public/config.json 中设置 errorEndpoint）
+ * --fail-rate 按比例返回 503，用于验证重试与离线队列。
+ */
+
+import http from 'http';
+
+const args = Object.fromEntries(
+  process.argv
+    .slice(2)
+    .filter(arg => arg.startsWith('--'))
+    .map(arg => arg.slice(2).split('='))
+);
+const port = Number(args.port || 4318);
+const failRate = Number(args['fail-rate'] || 0);
+
+const KIND_ICONS = { panic: '💥', boundary: '🧱', log: '📝' };
+
+const server = http.createServer((req, res) => {
+  // sendBeacon 与 fetch 都是跨域请求
+  res.setHeader('Access-Control-Allow-Origin', '*');
+  res.setHeader('Access-Control-Allow-Methods', 'POST, OPTIONS');
+  res.setHeader('Access-Control-Allow-Headers', 'Content-Type');
+
+  if (req.method === 'OPTIONS') {
+    res.writeHead(204).end();
+    return;
+  }
+  if (req.method !== 'POST' || !req.url.startsWith('/errors')) {
+    res.writeHead(404).end();
+    return;
+  }
+
+  let body = '';
+  req.on('data', chunk => (body += chunk));
+  req.on('end', () => {
+    if (Math.random() < failRate) {
+      console.log('⚠️  Simulated failure (503)');
+      res.writeHead(503).end();
+      return;
+    }
+
+    let batch;
+    try {
+      batch = JSON.parse(body);
+    } catch (error) {
+      console.warn(`❌ Invalid payload: ${error.message}`);
+      res.writeHead(400).end();
+      return;
+    }
+
+    const reports = batch.reports || [];
+    console.log(`\n📨 ${new Date().toISOString()} - ${reports.length} report(s), ${batch.dropped || 0} dropped`);
+    for (const report of reports) {
+      console.log(`   ${KIND_ICONS[report.kind] || '❓'} [${report.id}] ${report.kind}: ${report.message}`);
+      if (report.source) console.log(`      source: ${report.source}`);
+      console.log(`      route:  ${report.route}`);
+      console.log(`      build:  ${report.build} (${report.env})`);
+      console.log(`      agent:  ${report.userAgent}`);
+    }
+    res.writeHead(204).end();
+  });
+});
+
+server.listen(port, () => {
+  console.log(`🛰️  Error receiver listening on http://localhost:${port}/errors`);
+  if (failRate > 0) {
+    console.log(`   Simulating failures for ${Math.round(failRate * 100)}% of requests`);
+  }
+});
@@ -0,0 +1,48 @@
+/**
+ * GitHub Pages SPA 回退页面
+ * GitHub Pages 不支持把任意路径回退到 index.html，访问 `/<base>/md-demo` 会返回 404.html。
+ * 生成的 404.html 把原始路径编码进查询参数后跳转回应用根路径，
+ * 再由 `src/spa_redirect.rs` 在挂载应用前用 history.replaceState 还原真实地址。
+ */
+
+import fs from "fs";
+import path from "path";
+
+/** 查询参数名，与 `src/spa_redirect.rs` 中的 `PARAM` 保持一致 */
+const PARAM = "__path";
+
+/**
+ * 生成 404.html 的内容
+ * @param {string} baseUrl - 应用基础路径，以 `/` 开头和结尾
+ * @returns {string} HTML 源码
+ */
+export function spaFallbackHtml(baseUrl) {
+  return `<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset="utf-8" />
+    <title>Redirecting…</title>
+    <script>
+      (function () {
+        var base = ${JSON.stringify(baseUrl)};
+        var l = window.location;
+        var rest = l.pathname.indexOf(base) === 0 ? l.pathname.slice(base.length) : l.pathname.slice(1);
+        l.replace(base + "?${PARAM}=" + encodeURIComponent(rest + l.search + l.hash));
+      })();
+    </script>
+  </head>
+  <body></body>
+</html>
+`;
+}
+
+/**
+ * 在构建输出目录中写入 404.html
+ * @param {string} outDir - 构建输出目录
+ * @param {string} baseUrl - 应用基础路径
+ */
+export function writeSpaFallback(outDir, baseUrl = "/") {
+  const file = path.join(outDir, "404.html");
+  fs.writeFileSync(file, spaFallbackHtml(baseUrl));
+  console.log(`   ✅ Generated ${file} (base: ${baseUrl})`);
+}
@@ -0,0 +1,28 @@
+/**
+ * 拆分构建命令
+ * 启用 `split` feature 编译后，由 wasm-split-cli 按 `#[lazy]` 标记把页面拆分为独立的 wasm 模块。
+ * wasm-pack 无法在 wasm-bindgen 之后插入拆分步骤，因此这里直接调用 cargo 与 wasm-bindgen。
+ *
+ * 需要安装: cargo install wasm-bindgen-cli wasm-split-cli
+ */
+
+const CRATE_NAME = "leptos_vite_template";
+const TARGET = "wasm32-unknown-unknown";
+
+/**
+ * 获取拆分构建需要依次执行的命令
+ * @param {boolean} release - 是否为 release 构建
+ * @returns {string[]} 命令列表
+ */
+export function splitBuildCommands(release = false) {
+  const profile = release ? "release" : "debug";
+  const wasmFile = `target/${TARGET}/${profile}/${CRATE_NAME}.wasm`;
+  const splitCli = process.env.WASM_SPLIT_CLI || "wasm-split-cli";
+
+  return [
+    `cargo build --lib --target ${TARGET} --features split${release ? " --release" : ""}`,
+    // 保留链接器导出，wasm-split-cli 依靠它们识别拆分点
+    `wasm-bindgen ${wasmFile} --out-dir pkg --target web --keep-lld-exports`,
+    `${splitCli} pkg/${CRATE_NAME}_bg.wasm pkg`,
+  ];
+}
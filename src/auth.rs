@@ -0,0 +1,86 @@
+//! 认证上下文
+//!
+//! 保存当前登录的 [`User`]，并持久化到 sessionStorage，刷新页面后依然保持登录。
+//! 这里没有真实的后端，登录只是在客户端记录用户信息，用于演示路由守卫。
+
+use leptos::prelude::*;
+use web_sys::Storage;
+
+use crate::components::user_card::User;
+
+/// sessionStorage 中保存当前用户的键
+const STORAGE_KEY: &str = "auth-user";
+
+/// 登录页接收原始地址的查询参数名
+pub const REDIRECT_PARAM: &str = "redirect";
+
+/// 可选的用户角色
+pub const ROLES: &[&str] = &["开发者", "设计师", "产品经理"];
+
+/// 认证服务
+///
+/// 通过上下文共享，所有读取方法都会被响应式追踪。
+#[derive(Debug, Clone, Copy)]
+pub struct AuthContext {
+    user: RwSignal<Option<User>>,
+}
+
+impl AuthContext {
+    /// 从 sessionStorage 恢复登录状态
+    fn new() -> Self {
+        let user = storage()
+            .and_then(|s| s.get_item(STORAGE_KEY).ok().flatten())
+            .and_then(|stored| serde_json::from_str(&stored).ok());
+
+        Self {
+            user: RwSignal::new(user),
+        }
+    }
+
+    /// 当前登录的用户
+    pub fn user(&self) -> Option<User> {
+        self.user.get()
+    }
+
+    /// 是否已登录
+    pub fn is_authenticated(&self) -> bool {
+        self.user.with(Option::is_some)
+    }
+
+    /// 当前用户是否拥有指定角色
+    pub fn has_role(&self, role: &str) -> bool {
+        self.user
+            .with(|user| user.as_ref().is_some_and(|user| user.role == role))
+    }
+
+    /// 登录并持久化到 sessionStorage
+    pub fn login(&self, user: User) {
+        log::info!("Signed in as {} ({})", user.name, user.role);
+        if let (Some(storage), Ok(json)) = (storage(), serde_json::to_string(&user)) {
+            let _ = storage.set_item(STORAGE_KEY, &json);
+        }
+        self.user.set(Some(user));
+    }
+
+    /// 退出登录
+    pub fn logout(&self) {
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(STORAGE_KEY);
+        }
+        self.user.set(None);
+    }
+}
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.session_storage().ok().flatten()
+}
+
+/// 创建认证服务并注入上下文
+pub fn provide_auth() {
+    provide_context(AuthContext::new());
+}
+
+/// 获取认证服务
+pub fn use_auth() -> AuthContext {
+    expect_context::<AuthContext>()
+}
@@ -6,98 +6,352 @@ use wasm_bindgen_futures::JsFuture;
 use js_sys::*;
 use web_sys::*;
 
+use super::mdui_error::{ensure_loaded, MduiError};
+use super::mdui_options::*;
+
 // 使用传统的全局 MDUI API
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "mdui"])]
-    fn alert(options: &JsValue) -> js_sys::Promise;
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], catch)]
+    fn alert(options: &JsValue) -> Result<js_sys::Promise, JsValue>;
     
-    #[wasm_bindgen(js_namespace = ["window", "mdui"])]
-    fn confirm(options: &JsValue) -> js_sys::Promise;
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], catch)]
+    fn confirm(options: &JsValue) -> Result<js_sys::Promise, JsValue>;
     
-    #[wasm_bindgen(js_namespace = ["window", "mdui"])]
-    fn snackbar(options: &JsValue) -> js_sys::Promise;
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], catch)]
+    fn snackbar(options: &JsValue) -> Result<js_sys::Promise, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], catch)]
+    fn prompt(options: &JsValue) -> Result<js_sys::Promise, JsValue>;
+
+    // dialog 同步返回 <mdui-dialog> 元素，按钮点击通过 actions 中的回调通知
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], catch)]
+    fn dialog(options: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = setTheme, catch)]
+    fn set_theme(theme: &str) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = getTheme, catch)]
+    fn get_theme() -> Result<String, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = setColorScheme, catch)]
+    fn set_color_scheme(color: &str, options: &JsValue) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = removeColorScheme, catch)]
+    fn remove_color_scheme() -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = getColorFromImage, catch)]
+    fn get_color_from_image(image: &str) -> Result<js_sys::Promise, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], catch)]
+    fn breakpoint(width: &JsValue) -> Result<BreakpointQuery, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = observeResize, catch)]
+    fn observe_resize(target: &Element, callback: &Function) -> Result<ObserveResize, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = loadLocale, catch)]
+    fn load_locale(loader: &Function) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = setLocale, catch)]
+    fn set_locale(locale: &str) -> Result<js_sys::Promise, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = getLocale, catch)]
+    fn get_locale() -> Result<String, JsValue>;
+
+    // 由 plugins/mdui.ts 提供，通过打包工具加载 mdui/locales 下的语言包
+    #[wasm_bindgen(js_namespace = ["window", "mdui"], js_name = importLocale, catch)]
+    fn import_locale(locale: &str) -> Result<js_sys::Promise, JsValue>;
+
+    // breakpoint() 返回的断点查询对象
+    pub type BreakpointQuery;
+
+    #[wasm_bindgen(method, js_name = up)]
+    fn up_js(this: &BreakpointQuery, breakpoint: &str) -> bool;
+
+    #[wasm_bindgen(method, js_name = down)]
+    fn down_js(this: &BreakpointQuery, breakpoint: &str) -> bool;
+
+    #[wasm_bindgen(method, js_name = only)]
+    fn only_js(this: &BreakpointQuery, breakpoint: &str) -> bool;
+
+    #[wasm_bindgen(method, js_name = not)]
+    fn not_js(this: &BreakpointQuery, breakpoint: &str) -> bool;
+
+    #[wasm_bindgen(method, js_name = between)]
+    fn between_js(this: &BreakpointQuery, start: &str, end: &str) -> bool;
+
+    // observeResize() 返回的监听句柄
+    type ObserveResize;
+
+    #[wasm_bindgen(method)]
+    fn unobserve(this: &ObserveResize);
 }
 
-// 辅助函数：创建选项对象
-pub fn create_alert_options(headline: &str, description: &str) -> Result<JsValue, JsValue> {
-    let options = Object::new();
-    Reflect::set(&options, &"headline".into(), &headline.into())?;
-    Reflect::set(&options, &"description".into(), &description.into())?;
-    Ok(options.into())
+// 主题模式，对应 MDUI 在 <html> 上设置的 mdui-theme-* 类名
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+    Auto,
 }
 
-pub fn create_confirm_options(
-    headline: &str, 
-    description: &str,
-    confirm_text: Option<&str>,
-    cancel_text: Option<&str>
-) -> Result<JsValue, JsValue> {
-    let options = Object::new();
-    Reflect::set(&options, &"headline".into(), &headline.into())?;
-    Reflect::set(&options, &"description".into(), &description.into())?;
-    
-    if let Some(confirm) = confirm_text {
-        Reflect::set(&options, &"confirmText".into(), &confirm.into())?;
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Light, Theme::Dark, Theme::Auto];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::Auto => "auto",
+        }
     }
-    
-    if let Some(cancel) = cancel_text {
-        Reflect::set(&options, &"cancelText".into(), &cancel.into())?;
+
+    pub fn parse(mode: &str) -> Option<Theme> {
+        Theme::ALL.into_iter().find(|theme| theme.as_str() == mode)
     }
-    
-    Ok(options.into())
 }
 
-pub fn create_snackbar_options(
-    message: &str,
-    action: Option<&str>,
-    timeout: Option<u32>
-) -> Result<JsValue, JsValue> {
-    let options = Object::new();
-    Reflect::set(&options, &"message".into(), &message.into())?;
-    
-    if let Some(action_text) = action {
-        Reflect::set(&options, &"action".into(), &action_text.into())?;
+// MDUI 的断点，与 CSS 中的断点一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xxl,
+}
+
+impl Breakpoint {
+    pub const ALL: [Breakpoint; 6] = [
+        Breakpoint::Xs,
+        Breakpoint::Sm,
+        Breakpoint::Md,
+        Breakpoint::Lg,
+        Breakpoint::Xl,
+        Breakpoint::Xxl,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Breakpoint::Xs => "xs",
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+            Breakpoint::Xxl => "xxl",
+        }
     }
-    
-    if let Some(timeout_ms) = timeout {
-        Reflect::set(&options, &"timeout".into(), &timeout_ms.into())?;
+}
+
+impl BreakpointQuery {
+    // 宽度是否大于等于指定断点
+    pub fn up(&self, breakpoint: Breakpoint) -> bool {
+        self.up_js(breakpoint.as_str())
+    }
+
+    // 宽度是否小于指定断点
+    pub fn down(&self, breakpoint: Breakpoint) -> bool {
+        self.down_js(breakpoint.as_str())
+    }
+
+    // 宽度是否在指定断点的范围内
+    pub fn only(&self, breakpoint: Breakpoint) -> bool {
+        self.only_js(breakpoint.as_str())
+    }
+
+    // 宽度是否不在指定断点的范围内
+    pub fn not(&self, breakpoint: Breakpoint) -> bool {
+        self.not_js(breakpoint.as_str())
+    }
+
+    // 宽度是否在 start 与 end 之间
+    pub fn between(&self, start: Breakpoint, end: Breakpoint) -> bool {
+        self.between_js(start.as_str(), end.as_str())
+    }
+}
+
+// 元素尺寸监听，drop 时自动取消
+pub struct ResizeObservation {
+    handle: ObserveResize,
+    _callback: Closure<dyn FnMut(ResizeObserverEntry)>,
+}
+
+impl Drop for ResizeObservation {
+    fn drop(&mut self) {
+        self.handle.unobserve();
     }
-    
-    Ok(options.into())
 }
 
 // 高级 API 封装
+// 所有方法在 window.mdui 不存在时返回 MduiError::NotLoaded
 pub struct MduiService;
 
 impl MduiService {
-    pub async fn show_alert(headline: &str, description: &str) -> Result<(), JsValue> {
-        let options = create_alert_options(headline, description)?;
-        let promise = alert(&options); // alert 现在返回 Promise
-        JsFuture::from(promise).await?;
-        Ok(())
+    // 显示提示框，点击确认或以其他方式关闭后完成
+    pub async fn show_alert(options: &AlertOptions) -> Result<(), MduiError> {
+        ensure_loaded()?;
+        match settle(alert(&to_js(options)?)?).await {
+            Ok(_) | Err(MduiError::Dismissed) => Ok(()),
+            Err(err) => Err(err),
+        }
     }
     
-    pub async fn show_confirm(
-        headline: &str, 
-        description: &str,
-        confirm_text: Option<&str>,
-        cancel_text: Option<&str>
-    ) -> Result<bool, JsValue> {
-        let options = create_confirm_options(headline, description, confirm_text, cancel_text)?;
-        let promise = confirm(&options);
-        let result = JsFuture::from(promise).await?;
-        Ok(result.as_bool().unwrap_or(false))
+    // 显示确认框，点击确认时返回 true，取消或关闭时返回 false
+    pub async fn show_confirm(options: &ConfirmOptions) -> Result<bool, MduiError> {
+        ensure_loaded()?;
+        match settle(confirm(&to_js(options)?)?).await {
+            Ok(_) => Ok(true),
+            Err(MduiError::Dismissed) => Ok(false),
+            Err(err) => Err(err),
+        }
     }
     
-    pub async fn show_snackbar(
-        message: &str,
-        action: Option<&str>,
-        timeout: Option<u32>
-    ) -> Result<(), JsValue> {
-        let options = create_snackbar_options(message, action, timeout)?;
-        let promise = snackbar(&options); // snackbar 现在也返回 Promise
-        JsFuture::from(promise).await?;
+    pub async fn show_snackbar(options: &SnackbarOptions) -> Result<(), MduiError> {
+        ensure_loaded()?;
+        JsFuture::from(snackbar(&to_js(options)?)?).await?;
+        Ok(())
+    }
+
+    // 显示输入框，返回用户输入的内容；取消时返回 MduiError::Dismissed
+    pub async fn show_prompt(options: &PromptOptions) -> Result<String, MduiError> {
+        ensure_loaded()?;
+        let result = settle(prompt(&to_js(options)?)?).await?;
+        Ok(result.as_string().unwrap_or_default())
+    }
+
+    // 显示对话框，返回点击的按钮在 actions 中的序号；以其他方式关闭时返回 None
+    pub async fn show_dialog(options: &DialogOptions) -> Result<Option<usize>, MduiError> {
+        ensure_loaded()?;
+        let options = to_js(options)?;
+        let actions = Reflect::get(&options, &"actions".into())?;
+
+        // chosen 以按钮序号或 null 完成；closed 在对话框关闭后完成
+        let (chosen, resolve_chosen) = deferred();
+        let (closed, resolve_closed) = deferred();
+
+        let on_action = Closure::<dyn Fn(u32)>::new({
+            let resolve_chosen = resolve_chosen.clone();
+            move |index: u32| {
+                let _ = resolve_chosen.call1(&JsValue::NULL, &index.into());
+            }
+        });
+        if let Some(actions) = actions.dyn_ref::<Array>() {
+            let on_action: &Function = on_action.as_ref().unchecked_ref();
+            for (index, action) in actions.iter().enumerate() {
+                let on_click = on_action.bind1(&JsValue::NULL, &(index as u32).into());
+                Reflect::set(&action, &"onClick".into(), &on_click)?;
+            }
+        }
+
+        // 点击按钮时先以序号完成，之后的 onClosed 不再生效
+        let on_closed = Closure::<dyn Fn()>::new(move || {
+            let _ = resolve_chosen.call1(&JsValue::NULL, &JsValue::NULL);
+            let _ = resolve_closed.call0(&JsValue::NULL);
+        });
+        Reflect::set(&options, &"onClosed".into(), on_closed.as_ref())?;
+
+        dialog(&options)?;
+
+        // 关闭动画结束前回调仍可能被调用，等 onClosed 之后再释放
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = JsFuture::from(closed).await;
+            drop((on_action, on_closed));
+        });
+
+        let index = JsFuture::from(chosen).await?;
+        Ok(index.as_f64().map(|index| index as usize))
+    }
+
+    pub fn set_theme(theme: Theme) -> Result<(), MduiError> {
+        ensure_loaded()?;
+        Ok(set_theme(theme.as_str())?)
+    }
+
+    pub fn get_theme() -> Result<Theme, MduiError> {
+        ensure_loaded()?;
+        let theme = get_theme()?;
+        Ok(Theme::parse(&theme).unwrap_or_default())
+    }
+
+    // 根据主色生成配色方案
+    pub fn set_color_scheme(color: &str, options: &ColorSchemeOptions) -> Result<(), MduiError> {
+        ensure_loaded()?;
+        Ok(set_color_scheme(color, &to_js(options)?)?)
+    }
+
+    pub fn remove_color_scheme() -> Result<(), MduiError> {
+        ensure_loaded()?;
+        Ok(remove_color_scheme()?)
+    }
+
+    // 提取图片的主色，image 为图片地址
+    pub async fn get_color_from_image(image: &str) -> Result<String, MduiError> {
+        ensure_loaded()?;
+        let color = JsFuture::from(get_color_from_image(image)?).await?;
+        Ok(color.as_string().unwrap_or_default())
+    }
+
+    // 断点查询，width 为 None 时使用当前窗口宽度
+    pub fn breakpoint(width: Option<f64>) -> Result<BreakpointQuery, MduiError> {
+        ensure_loaded()?;
+        let width = width.map(JsValue::from).unwrap_or(JsValue::UNDEFINED);
+        Ok(breakpoint(&width)?)
+    }
+
+    // 监听元素尺寸变化，返回值被 drop 时停止监听
+    pub fn observe_resize(
+        target: &Element,
+        callback: impl FnMut(ResizeObserverEntry) + 'static
+    ) -> Result<ResizeObservation, MduiError> {
+        ensure_loaded()?;
+        let callback = Closure::<dyn FnMut(ResizeObserverEntry)>::new(callback);
+        let handle = observe_resize(target, callback.as_ref().unchecked_ref())?;
+        Ok(ResizeObservation { handle, _callback: callback })
+    }
+
+    // 注册语言包加载函数，loader 接收语言代码并返回加载语言包的 Promise
+    pub fn load_locale(loader: &Function) -> Result<(), MduiError> {
+        ensure_loaded()?;
+        Ok(load_locale(loader)?)
+    }
+
+    // 使用随应用打包的 MDUI 语言包
+    pub fn load_bundled_locales() -> Result<(), MduiError> {
+        BUNDLED_LOCALE_LOADER.with(|loader| Self::load_locale(loader.as_ref().unchecked_ref()))
+    }
+
+    // 切换语言，语言包加载完成后完成
+    pub async fn set_locale(locale: &str) -> Result<(), MduiError> {
+        ensure_loaded()?;
+        JsFuture::from(set_locale(locale)?).await?;
         Ok(())
     }
+
+    pub fn get_locale() -> Result<String, MduiError> {
+        ensure_loaded()?;
+        Ok(get_locale()?)
+    }
+}
+
+thread_local! {
+    // load_bundled_locales 使用的加载函数，只创建一次，重复调用时复用
+    static BUNDLED_LOCALE_LOADER: Closure<dyn Fn(String) -> Promise> =
+        Closure::new(|locale: String| {
+            import_locale(&locale).unwrap_or_else(|err| Promise::reject(&err))
+        });
+}
+
+// 创建一个 Promise 并取出其 resolve 函数
+fn deferred() -> (Promise, Function) {
+    let mut resolve = None;
+    // Promise 的 executor 同步执行
+    let promise = Promise::new(&mut |res, _| resolve = Some(res));
+    (promise, resolve.expect("Promise executor runs synchronously"))
+}
+
+// 等待对话框类函数的 Promise，用户关闭对话框时为 MduiError::Dismissed
+async fn settle(promise: Promise) -> Result<JsValue, MduiError> {
+    JsFuture::from(promise).await.map_err(MduiError::from_rejection)
 }
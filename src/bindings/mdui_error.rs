@@ -0,0 +1,103 @@
+//! MDUI 调用的错误
+//!
+//! [`MduiService`](super::mdui::MduiService) 的方法都返回 [`MduiError`]。它实现了 [`std::error::Error`]，
+//! 可以直接用 `?` 交给 `<ErrorBoundary>`，也可以按变体分别处理，例如忽略用户取消的输入框。
+
+use std::fmt;
+
+use wasm_bindgen::{JsCast, JsValue};
+
+/// MDUI 调用失败的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MduiError {
+    /// 页面中没有 `window.mdui`，通常是 `plugins/mdui.ts` 尚未加载
+    NotLoaded,
+    /// 选项无法转换为 JavaScript 对象
+    InvalidOptions(String),
+    /// 用户点击取消、按下 Esc 或点击遮罩层关闭了对话框
+    Dismissed,
+    /// MDUI 抛出的其他异常
+    JsException(String),
+}
+
+impl MduiError {
+    /// 从 MDUI 抛出或 reject 的值转换
+    ///
+    /// `window.mdui` 不存在时为 [`MduiError::NotLoaded`]，否则提取异常消息。
+    pub fn from_js(value: JsValue) -> Self {
+        if !is_loaded() {
+            return Self::NotLoaded;
+        }
+        Self::JsException(js_message(&value))
+    }
+
+    /// 对话框 Promise 被 reject 时的转换，MDUI 在用户关闭对话框时以 `undefined` reject
+    pub(crate) fn from_rejection(value: JsValue) -> Self {
+        if value.is_undefined() {
+            Self::Dismissed
+        } else {
+            Self::from_js(value)
+        }
+    }
+}
+
+impl fmt::Display for MduiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotLoaded => write!(f, "MDUI is not loaded (window.mdui is undefined)"),
+            Self::InvalidOptions(message) => write!(f, "invalid MDUI options: {message}"),
+            Self::Dismissed => write!(f, "dismissed by the user"),
+            Self::JsException(message) => write!(f, "MDUI error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for MduiError {}
+
+impl From<JsValue> for MduiError {
+    fn from(value: JsValue) -> Self {
+        Self::from_js(value)
+    }
+}
+
+impl From<serde_wasm_bindgen::Error> for MduiError {
+    fn from(error: serde_wasm_bindgen::Error) -> Self {
+        Self::InvalidOptions(error.to_string())
+    }
+}
+
+/// 导出给 JavaScript 的函数以 `Error` 对象抛出
+impl From<MduiError> for JsValue {
+    fn from(error: MduiError) -> Self {
+        js_sys::Error::new(&error.to_string()).into()
+    }
+}
+
+/// 调用前检查 `window.mdui` 是否存在
+pub(crate) fn ensure_loaded() -> Result<(), MduiError> {
+    if is_loaded() {
+        Ok(())
+    } else {
+        Err(MduiError::NotLoaded)
+    }
+}
+
+fn is_loaded() -> bool {
+    web_sys::window()
+        .and_then(|window| js_sys::Reflect::get(&window, &"mdui".into()).ok())
+        .is_some_and(|mdui| mdui.is_object())
+}
+
+/// 异常消息：`Error` 对象取 `name: message`，字符串原样使用，其他值转为 JSON
+fn js_message(value: &JsValue) -> String {
+    if let Some(error) = value.dyn_ref::<js_sys::Error>() {
+        return String::from(error.to_string());
+    }
+    if let Some(message) = value.as_string() {
+        return message;
+    }
+    js_sys::JSON::stringify(value)
+        .ok()
+        .and_then(|json| json.as_string())
+        .unwrap_or_else(|| format!("{value:?}"))
+}
@@ -0,0 +1,529 @@
+//! MDUI 函数的选项
+//!
+//! 字段与 MDUI 文档中的选项一一对应，序列化为 camelCase，未设置的字段不会出现在对象中，
+//! 由 MDUI 使用默认值。回调类的选项（`onConfirm`、`onClick` 等）无法序列化，
+//! 需要时由 [`MduiService`](super::mdui::MduiService) 在转换后单独设置。
+//!
+//! ```ignore
+//! let options = SnackbarOptions::new("已保存")
+//!     .action("撤销")
+//!     .placement(SnackbarPlacement::BottomEnd)
+//!     .auto_close_delay(3000);
+//! MduiService::show_snackbar(&options).await?;
+//! ```
+
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+/// 转换为传给 MDUI 的 JavaScript 对象
+///
+/// 使用 JSON 兼容的序列化方式：`None` 与单元值转为 `null`、映射转为普通对象，
+/// 得到的对象与 `serde_json` 生成的 JSON 结构相同。
+pub fn to_js(options: &impl Serialize) -> Result<JsValue, serde_wasm_bindgen::Error> {
+    options.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+}
+
+/// 为选项结构体生成链式设置方法，`impl String` 表示参数接受 `impl Into<String>`
+macro_rules! setters {
+    () => {};
+    ($(#[$doc:meta])* $field:ident: impl $ty:ty, $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+            self.$field = Some($field.into());
+            self
+        }
+        setters!($($rest)*);
+    };
+    ($(#[$doc:meta])* $field:ident: $ty:ty, $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.$field = Some($field);
+            self
+        }
+        setters!($($rest)*);
+    };
+}
+
+/// `alert()` 的选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_esc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_overlay_click: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+}
+
+impl AlertOptions {
+    pub fn new(headline: impl Into<String>) -> Self {
+        Self::default().headline(headline)
+    }
+
+    setters! {
+        headline: impl String,
+        description: impl String,
+        /// Material Icons 图标名
+        icon: impl String,
+        /// 按下 Esc 时是否关闭
+        close_on_esc: bool,
+        /// 点击遮罩层时是否关闭
+        close_on_overlay_click: bool,
+        confirm_text: impl String,
+        /// 队列名，同一队列中的对话框依次显示
+        queue: impl String,
+    }
+}
+
+/// `confirm()` 的选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_esc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_overlay_click: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+}
+
+impl ConfirmOptions {
+    pub fn new(headline: impl Into<String>) -> Self {
+        Self::default().headline(headline)
+    }
+
+    setters! {
+        headline: impl String,
+        description: impl String,
+        /// Material Icons 图标名
+        icon: impl String,
+        /// 按下 Esc 时是否关闭
+        close_on_esc: bool,
+        /// 点击遮罩层时是否关闭
+        close_on_overlay_click: bool,
+        confirm_text: impl String,
+        cancel_text: impl String,
+        /// 队列名，同一队列中的对话框依次显示
+        queue: impl String,
+    }
+}
+
+/// `prompt()` 的选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_esc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_overlay_click: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_field_options: Option<TextFieldOptions>,
+}
+
+impl PromptOptions {
+    pub fn new(headline: impl Into<String>) -> Self {
+        Self::default().headline(headline)
+    }
+
+    setters! {
+        headline: impl String,
+        description: impl String,
+        /// Material Icons 图标名
+        icon: impl String,
+        /// 按下 Esc 时是否关闭
+        close_on_esc: bool,
+        /// 点击遮罩层时是否关闭
+        close_on_overlay_click: bool,
+        confirm_text: impl String,
+        cancel_text: impl String,
+        /// 队列名，同一队列中的对话框依次显示
+        queue: impl String,
+        /// 输入框 `<mdui-text-field>` 的属性
+        text_field_options: TextFieldOptions,
+    }
+}
+
+/// 输入框的类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextFieldType {
+    Text,
+    Number,
+    Password,
+    Url,
+    Email,
+    Search,
+    Tel,
+    Date,
+    DatetimeLocal,
+    Month,
+    Time,
+    Week,
+}
+
+/// `prompt()` 中输入框的属性
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextFieldOptions {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<TextFieldType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub helper: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxlength: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counter: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+}
+
+impl TextFieldOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    setters! {
+        r#type: TextFieldType,
+        /// 初始值
+        value: impl String,
+        label: impl String,
+        placeholder: impl String,
+        /// 输入框下方的帮助文本
+        helper: impl String,
+        /// 是否显示清空按钮
+        clearable: bool,
+        maxlength: u32,
+        /// 是否显示字数统计，需要同时设置 `maxlength`
+        counter: bool,
+        required: bool,
+    }
+}
+
+/// `dialog()` 的选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 对话框正文的 HTML
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_esc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_overlay_click: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<DialogAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stacked_actions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fullscreen: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+}
+
+impl DialogOptions {
+    pub fn new(headline: impl Into<String>) -> Self {
+        Self::default().headline(headline)
+    }
+
+    /// 添加一个底部按钮，按钮按添加顺序编号
+    pub fn action(mut self, text: impl Into<String>) -> Self {
+        self.actions.push(DialogAction { text: text.into() });
+        self
+    }
+
+    setters! {
+        headline: impl String,
+        description: impl String,
+        /// 对话框正文的 HTML
+        body: impl String,
+        /// Material Icons 图标名
+        icon: impl String,
+        /// 按下 Esc 时是否关闭
+        close_on_esc: bool,
+        /// 点击遮罩层时是否关闭
+        close_on_overlay_click: bool,
+        /// 按钮是否垂直排列
+        stacked_actions: bool,
+        fullscreen: bool,
+        /// 队列名，同一队列中的对话框依次显示
+        queue: impl String,
+    }
+}
+
+/// 对话框底部的按钮，点击回调由 `MduiService::show_dialog` 设置
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DialogAction {
+    pub text: String,
+}
+
+/// Snackbar 的位置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SnackbarPlacement {
+    Top,
+    TopStart,
+    TopEnd,
+    #[default]
+    Bottom,
+    BottomStart,
+    BottomEnd,
+}
+
+/// `snackbar()` 的选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnackbarOptions {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement: Option<SnackbarPlacement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closeable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_line: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_close_delay: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_outside_click: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+}
+
+impl SnackbarOptions {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+
+    setters! {
+        placement: SnackbarPlacement,
+        /// 操作按钮的文本
+        action: impl String,
+        /// 是否显示关闭按钮
+        closeable: bool,
+        /// 消息的最大行数，1 或 2
+        message_line: u8,
+        /// 自动关闭的延迟（毫秒），0 表示不自动关闭
+        auto_close_delay: u32,
+        /// 点击其他区域时是否关闭
+        close_on_outside_click: bool,
+        /// 队列名，同一队列中的 Snackbar 依次显示
+        queue: impl String,
+    }
+}
+
+/// `setColorScheme()` 的选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorSchemeOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_colors: Vec<CustomColor>,
+}
+
+impl ColorSchemeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加自定义颜色，生成 `--mdui-color-{name}` 等 CSS 变量
+    pub fn custom_color(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_colors.push(CustomColor {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+}
+
+/// 自定义颜色
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CustomColor {
+    pub name: String,
+    /// 十六进制颜色值
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    //! `serde_wasm_bindgen` 只能在 wasm 中运行，这里用 `serde_json` 检查对象结构。
+    //! [`to_js`] 使用 `Serializer::json_compatible()`，两者的输出一致：选项中没有映射与 `u64`，
+    //! 未设置的字段都被 `skip_serializing_if` 跳过，枚举都是序列化为字符串的单元变体。
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn alert_uses_mdui_option_names() {
+        let options = AlertOptions::new("标题")
+            .description("内容")
+            .icon("info")
+            .close_on_esc(false)
+            .close_on_overlay_click(true)
+            .confirm_text("好的")
+            .queue("dialogs");
+        assert_eq!(
+            to_value(&options).unwrap(),
+            json!({
+                "headline": "标题",
+                "description": "内容",
+                "icon": "info",
+                "closeOnEsc": false,
+                "closeOnOverlayClick": true,
+                "confirmText": "好的",
+                "queue": "dialogs",
+            })
+        );
+    }
+
+    #[test]
+    fn unset_fields_are_omitted() {
+        assert_eq!(
+            to_value(AlertOptions::new("标题")).unwrap(),
+            json!({ "headline": "标题" })
+        );
+        assert_eq!(to_value(DialogOptions::default()).unwrap(), json!({}));
+        assert_eq!(to_value(ColorSchemeOptions::new()).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn confirm_uses_mdui_option_names() {
+        let options = ConfirmOptions::new("确认")
+            .confirm_text("确定")
+            .cancel_text("取消")
+            .close_on_esc(true);
+        assert_eq!(
+            to_value(&options).unwrap(),
+            json!({
+                "headline": "确认",
+                "confirmText": "确定",
+                "cancelText": "取消",
+                "closeOnEsc": true,
+            })
+        );
+    }
+
+    #[test]
+    fn prompt_nests_text_field_options() {
+        let options = PromptOptions::new("名字").text_field_options(
+            TextFieldOptions::new()
+                .r#type(TextFieldType::DatetimeLocal)
+                .value("Leptos")
+                .maxlength(20)
+                .counter(true),
+        );
+        assert_eq!(
+            to_value(&options).unwrap(),
+            json!({
+                "headline": "名字",
+                "textFieldOptions": {
+                    "type": "datetime-local",
+                    "value": "Leptos",
+                    "maxlength": 20,
+                    "counter": true,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn dialog_actions_keep_order() {
+        let options = DialogOptions::new("更新")
+            .body("<p>正文</p>")
+            .action("稍后")
+            .action("立即更新")
+            .stacked_actions(true)
+            .fullscreen(false);
+        assert_eq!(
+            to_value(&options).unwrap(),
+            json!({
+                "headline": "更新",
+                "body": "<p>正文</p>",
+                "actions": [{ "text": "稍后" }, { "text": "立即更新" }],
+                "stackedActions": true,
+                "fullscreen": false,
+            })
+        );
+    }
+
+    #[test]
+    fn snackbar_uses_mdui_option_names() {
+        let options = SnackbarOptions::new("已保存")
+            .placement(SnackbarPlacement::BottomEnd)
+            .action("撤销")
+            .closeable(true)
+            .message_line(2)
+            .auto_close_delay(3000)
+            .close_on_outside_click(false)
+            .queue("toasts");
+        assert_eq!(
+            to_value(&options).unwrap(),
+            json!({
+                "message": "已保存",
+                "placement": "bottom-end",
+                "action": "撤销",
+                "closeable": true,
+                "messageLine": 2,
+                "autoCloseDelay": 3000,
+                "closeOnOutsideClick": false,
+                "queue": "toasts",
+            })
+        );
+    }
+
+    #[test]
+    fn color_scheme_custom_colors() {
+        let options = ColorSchemeOptions::new().custom_color("brand", "#ff5722");
+        assert_eq!(
+            to_value(&options).unwrap(),
+            json!({ "customColors": [{ "name": "brand", "value": "#ff5722" }] })
+        );
+    }
+}
@@ -1,2 +1,4 @@
 // JavaScript 绑定模块
 pub mod mdui;
+pub mod mdui_error;
+pub mod mdui_options;
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+use wasm_bindgen::JsCast;
+
+use crate::debug;
+use crate::env::{get_debug_info, use_app_config};
+use crate::logging;
+
+/// 浮层中显示的日志条数
+const RECENT_LOGS: usize = 10;
+
+/// 内存占用的刷新间隔
+const MEMORY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 调试浮层 - 仅存在于 debug 构建中，按 Ctrl+Shift+D 切换显示
+#[component]
+pub fn DebugOverlay() -> impl IntoView {
+    let config = use_app_config();
+    let location = use_location();
+    let visible = RwSignal::new(false);
+    let memory = RwSignal::new(memory_size());
+
+    let keydown = window_event_listener(ev::keydown, move |ev| {
+        if ev.ctrl_key() && ev.shift_key() && ev.key().eq_ignore_ascii_case("d") {
+            ev.prevent_default();
+            visible.update(|visible| *visible = !*visible);
+        }
+    });
+    on_cleanup(move || keydown.remove());
+
+    // wasm 线性内存只会增长不会触发响应式更新，因此定时采样
+    if let Ok(interval) =
+        set_interval_with_handle(move || memory.set(memory_size()), MEMORY_POLL_INTERVAL)
+    {
+        on_cleanup(move || interval.clear());
+    }
+
+    let current_route = move || {
+        let search = location.search.get();
+        let query = if search.is_empty() {
+            String::new()
+        } else {
+            format!("?{search}")
+        };
+        format!("{}{}{}", location.pathname.get(), query, location.hash.get())
+    };
+
+    let recent_logs = move || {
+        let records = logging::records();
+        let skip = records.len().saturating_sub(RECENT_LOGS);
+        records
+            .into_iter()
+            .skip(skip)
+            .map(|entry| {
+                view! { <li>{format!("[{} {}] {}", entry.level, entry.target, entry.message)}</li> }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <Show when=move || visible.get()>
+            <div class="debug-overlay">
+                <pre>{move || config.with(get_debug_info)}</pre>
+                <dl>
+                    <dt>"当前路由"</dt>
+                    <dd>{current_route}</dd>
+                    <dt>"Router base"</dt>
+                    <dd>{move || config.with(|c| c.base_url.clone())}</dd>
+                    <dt>"Wasm 内存"</dt>
+                    <dd>{move || format!("{:.2} MiB", memory.get() as f64 / (1024.0 * 1024.0))}</dd>
+                    <dt>"存活 Owner"</dt>
+                    <dd>{debug::live_owners}</dd>
+                </dl>
+                <p>"最近日志"</p>
+                <ul>{recent_logs}</ul>
+                <small>"Ctrl+Shift+D 关闭"</small>
+            </div>
+        </Show>
+    }
+}
+
+/// wasm 线性内存的当前大小（字节）
+fn memory_size() -> u32 {
+    wasm_bindgen::memory()
+        .unchecked_into::<js_sys::WebAssembly::Memory>()
+        .buffer()
+        .unchecked_into::<js_sys::ArrayBuffer>()
+        .byte_length()
+}
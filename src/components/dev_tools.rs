@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::env::use_app_config;
+use crate::features::use_feature_flags;
+use crate::routes::Page;
+
+/// 开发者工具 - 仅在开发环境中挂载
+#[component]
+pub fn DevTools() -> impl IntoView {
+    let config = use_app_config();
+    let flags = use_feature_flags();
+
+    view! {
+        <details class="dev-tools" title="Development build">
+            <summary>"🛠️ " {move || config.with(|c| c.env.to_string())}</summary>
+
+            <p>"功能开关"</p>
+            <ul>
+                <For
+                    each=move || flags.all()
+                    key=|(name, enabled)| (name.clone(), *enabled)
+                    children=move |(name, enabled)| {
+                        let flag = name.clone();
+                        view! {
+                            <li>
+                                <label>
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=enabled
+                                        on:change=move |_| flags.set(&flag, !enabled)
+                                    />
+                                    {name}
+                                </label>
+                            </li>
+                        }
+                    }
+                />
+            </ul>
+            <button on:click=move |_| flags.reset()>"恢复默认"</button>
+
+            <p>
+                <A href=Page::Logs.href()>"📜 日志查看器"</A>
+            </p>
+        </details>
+    }
+}
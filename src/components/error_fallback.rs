@@ -0,0 +1,78 @@
+use leptos::error::ErrorId;
+use leptos::prelude::*;
+
+use crate::crash::error_id;
+use crate::env::use_app_config;
+use crate::reporting::{self, ReportKind};
+
+/// 根部 `<ErrorBoundary>` 的回退界面
+///
+/// 显示错误编号与重新加载按钮，开发环境下额外列出错误内容。
+/// 错误消失（例如重新提交表单成功）后边界会自动恢复显示原内容。
+#[component]
+pub fn ErrorFallback(errors: ArcRwSignal<Errors>) -> impl IntoView {
+    let show_details = use_app_config().with_untracked(|c| c.env.shows_debug_info());
+    // 边界捕获的每个错误各自生成一个错误编号，只在第一次出现时上报
+    let reported = RwSignal::new(Vec::<(ErrorId, String)>::new());
+
+    Effect::new({
+        let errors = errors.clone();
+        move |_| {
+            errors.with(|errors| {
+                reported.maybe_update(|reported| {
+                    let before = reported.len();
+                    reported.retain(|(key, _)| errors.iter().any(|(current, _)| current == key));
+                    let mut changed = reported.len() != before;
+                    for (key, error) in errors.iter() {
+                        if reported.iter().any(|(seen, _)| seen == key) {
+                            continue;
+                        }
+                        let id = error_id();
+                        log::error!(target: reporting::TARGET, "[{id}] {error}");
+                        reporting::report(
+                            id.clone(),
+                            ReportKind::Boundary,
+                            error.to_string(),
+                            None,
+                        );
+                        reported.push((key.clone(), id));
+                        changed = true;
+                    }
+                    changed
+                })
+            })
+        }
+    });
+
+    let ids = move || {
+        reported.with(|reported| {
+            reported
+                .iter()
+                .map(|(_, id)| id.as_str())
+                .collect::<Vec<_>>()
+                .join("、")
+        })
+    };
+
+    let details = move || {
+        errors
+            .get()
+            .into_iter()
+            .map(|(_, error)| view! { <li>{error.to_string()}</li> })
+            .collect_view()
+    };
+
+    view! {
+        <div class="container error-fallback" role="alert">
+            <h1>"😵 出错了"</h1>
+            <p style="color: #666;">"页面加载时发生错误，请稍后重试。反馈问题时请附上错误编号。"</p>
+            <p style="font-family: monospace; color: #c0392b;">"错误编号：" {ids}</p>
+            <Show when=move || show_details>
+                <ul style="text-align: left; color: #c0392b;">{details.clone()}</ul>
+            </Show>
+            <button on:click=|_| {
+                let _ = window().location().reload();
+            }>"重新加载"</button>
+        </div>
+    }
+}
@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+use crate::features::use_feature_flags;
+
+/// [`Feature`] 关闭时显示的内容
+#[slot]
+pub struct Fallback {
+    children: ChildrenFn,
+}
+
+/// 根据功能开关渲染内容
+///
+/// ```ignore
+/// <Feature name="new-page">
+///     <Fallback slot>"敬请期待"</Fallback>
+///     <NewPage />
+/// </Feature>
+/// ```
+#[component]
+pub fn Feature(
+    #[prop(into)] name: String,
+    #[prop(optional)] fallback: Option<Fallback>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let enabled = use_feature_flags().signal(name);
+
+    view! {
+        <Show
+            when=move || enabled.get()
+            fallback=move || fallback.as_ref().map(|fallback| (fallback.children)())
+        >
+            {children()}
+        </Show>
+    }
+}
@@ -2,3 +2,11 @@ pub mod callback_test;
 pub mod counter_btn;
 pub mod user_card;
 pub mod mdui_components;
+pub mod dev_tools;
+pub mod error_fallback;
+pub mod feature;
+pub mod nav_bar;
+pub mod protected_route;
+pub mod scroll_restoration;
+#[cfg(debug_assertions)]
+pub mod debug_overlay;
@@ -0,0 +1,56 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::auth::use_auth;
+use crate::env::use_app_config;
+use crate::features::use_feature_flags;
+use crate::routes::{nav_routes, Page};
+
+/// 导航栏 - 由路由表生成，隐藏当前环境未注册或功能开关关闭的页面
+///
+/// 鼠标悬停在链接上时预取懒加载页面的模块。
+#[component]
+pub fn NavBar() -> impl IntoView {
+    let env = use_app_config().with_untracked(|c| c.env);
+    let flags = use_feature_flags();
+    let auth = use_auth();
+
+    let links = move || {
+        nav_routes(env)
+            .into_iter()
+            .filter(|route| route.feature.is_none_or(|name| flags.is_enabled(name)))
+            .enumerate()
+            .map(|(index, route)| {
+                view! {
+                    {(index > 0).then_some(" | ")}
+                    <A href=route.path on:mouseenter=move |_| route.prefetch()>
+                        {route.icon} " " {route.title}
+                    </A>
+                }
+            })
+            .collect_view()
+    };
+
+    let auth_status = move || match auth.user() {
+        Some(user) => view! {
+            <span class="auth-status">
+                {format!("👤 {}（{}）", user.name, user.role)} " "
+                <button on:click=move |_| auth.logout()>"退出"</button>
+            </span>
+        }
+        .into_any(),
+        None => view! {
+            <span class="auth-status">
+                <A href=Page::Login.href()>"🔑 登录"</A>
+            </span>
+        }
+        .into_any(),
+    };
+
+    view! {
+        <nav>
+            {links}
+            {auth_status}
+        </nav>
+    }
+}
@@ -0,0 +1,61 @@
+use leptos::prelude::*;
+use leptos_router::components::Redirect;
+use leptos_router::hooks::use_location;
+
+use crate::auth::{use_auth, REDIRECT_PARAM};
+use crate::env::use_app_config;
+use crate::routes::Page;
+
+/// 受保护的页面 - 未登录时跳转到登录页，登录后再回到当前地址
+///
+/// 设置 `role` 时还要求当前用户拥有该角色，否则显示无权访问的提示。
+#[component]
+pub fn ProtectedRoute(
+    /// 需要的角色，未设置时只要求已登录
+    #[prop(optional, into)]
+    role: Option<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let auth = use_auth();
+    let location = use_location();
+    let base_url = use_app_config().with_untracked(|c| c.base_url.clone());
+
+    move || {
+        if !auth.is_authenticated() {
+            // 记录相对于 base 的原始地址，登录页导航时路由器会重新加上 base
+            let pathname = location.pathname.get_untracked();
+            let path = pathname
+                .strip_prefix(base_url.trim_end_matches('/'))
+                .unwrap_or(&pathname);
+            // 路由器提供的查询串不带开头的 `?`
+            let search = location.search.get_untracked();
+            let query = if search.is_empty() {
+                String::new()
+            } else {
+                format!("?{search}")
+            };
+            let original = format!("{}{}{}", path, query, location.hash.get_untracked());
+            let login = format!(
+                "/{}?{}={}",
+                Page::Login.href(),
+                REDIRECT_PARAM,
+                js_sys::encode_uri_component(&original)
+            );
+            return view! { <Redirect path=login /> }.into_any();
+        }
+
+        match &role {
+            Some(role) if !auth.has_role(role) => {
+                let role = role.clone();
+                view! {
+                    <div class="container">
+                        <h2>"🔒 无权访问"</h2>
+                        <p>"该页面需要 " <strong>{role}</strong> " 角色。"</p>
+                    </div>
+                }
+                .into_any()
+            }
+            _ => children().into_any(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use leptos::ev;
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+use web_sys::ScrollRestoration as HistoryScrollRestoration;
+
+/// 后退/前进时恢复滚动位置最多尝试的帧数，页面内容可能在几帧之后才撑开高度
+const RESTORE_FRAMES: u32 = 10;
+
+/// 滚动位置管理 - 需要放在 `<Router>` 内部
+///
+/// 后退/前进时恢复离开该地址时的滚动位置；其他导航滚动到 `#fragment` 指向的元素，
+/// 没有片段时回到顶部，只改变查询参数的导航不滚动。
+/// `is_routing` 为路由器的加载状态，懒加载页面下载期间旧页面仍在显示，要等新页面渲染后再处理滚动。
+#[component]
+pub fn ScrollRestoration(#[prop(into)] is_routing: Signal<bool>) -> impl IntoView {
+    let location = use_location();
+    let positions = StoredValue::new(HashMap::<String, (f64, f64)>::new());
+    let current = StoredValue::new(String::new());
+    let current_target = StoredValue::new(String::new());
+    let popped = StoredValue::new(false);
+
+    // 由应用接管滚动恢复，浏览器自带的恢复会在新页面渲染前生效
+    if let Ok(history) = window().history() {
+        let _ = history.set_scroll_restoration(HistoryScrollRestoration::Manual);
+    }
+
+    let scroll = window_event_listener(ev::scroll, move |_| {
+        let position = scroll_position();
+        positions.update_value(|positions| {
+            positions.insert(current.get_value(), position);
+        });
+    });
+    let popstate = window_event_listener(ev::popstate, move |_| popped.set_value(true));
+    on_cleanup(move || {
+        scroll.remove();
+        popstate.remove();
+    });
+
+    Effect::new(move |_| {
+        let key = format!(
+            "{}{}{}",
+            location.pathname.get(),
+            location.search.get(),
+            location.hash.get()
+        );
+        if is_routing.get() || current.with_value(|current| *current == key) {
+            return;
+        }
+
+        let first = current.with_value(String::is_empty);
+        current.set_value(key.clone());
+        // 只有查询参数变化时（例如同步到 URL 的页面状态）保持当前位置
+        let target = format!(
+            "{}{}",
+            location.pathname.get_untracked(),
+            location.hash.get_untracked()
+        );
+        let same_target = current_target.with_value(|current| *current == target);
+        current_target.set_value(target);
+        let back_forward = popped.get_value();
+        popped.set_value(false);
+
+        let saved = positions.with_value(|positions| positions.get(&key).copied());
+        match saved.filter(|_| back_forward) {
+            Some((x, y)) => request_animation_frame(move || restore(x, y, RESTORE_FRAMES)),
+            // 首次加载时只处理片段，保留浏览器的初始位置
+            None if first && location.hash.get_untracked().is_empty() => {}
+            None if same_target => {}
+            None => {
+                let hash = location.hash.get_untracked();
+                request_animation_frame(move || scroll_to_target(&hash));
+            }
+        }
+    });
+}
+
+fn scroll_position() -> (f64, f64) {
+    let window = window();
+    (
+        window.scroll_x().unwrap_or_default(),
+        window.scroll_y().unwrap_or_default(),
+    )
+}
+
+/// 滚动到指定位置，页面高度不足时在后续帧中重试
+fn restore(x: f64, y: f64, frames: u32) {
+    window().scroll_to_with_x_and_y(x, y);
+    let (_, actual) = scroll_position();
+    if (actual - y).abs() > 1.0 && frames > 0 {
+        request_animation_frame(move || restore(x, y, frames - 1));
+    }
+}
+
+/// 滚动到片段指向的元素，没有片段或元素不存在时回到顶部
+fn scroll_to_target(hash: &str) {
+    let id = hash.strip_prefix('#').unwrap_or(hash);
+    let id = js_sys::decode_uri_component(id)
+        .ok()
+        .and_then(|id| id.as_string())
+        .unwrap_or_else(|| id.to_string());
+    match (!id.is_empty())
+        .then(|| document().get_element_by_id(&id))
+        .flatten()
+    {
+        Some(element) => element.scroll_into_view(),
+        None => window().scroll_to_with_x_and_y(0.0, 0.0),
+    }
+}
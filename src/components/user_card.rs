@@ -11,8 +11,9 @@ pub struct User {
     pub role: String,
 }
 
-// 定义主题配置结构
-#[derive(Debug, Clone, PartialEq)]
+// 定义主题配置结构，可以通过查询参数恢复，缺失的字段使用默认值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CardConfig {
     pub theme: String,
     pub show_email: bool,
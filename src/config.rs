@@ -0,0 +1,5 @@
+//! 编译时配置
+//!
+//! 由 `build.rs` 根据 `.env*` 文件校验并生成，缺失或格式错误的配置会直接导致编译失败。
+
+include!(concat!(env!("OUT_DIR"), "/config.rs"));
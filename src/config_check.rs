@@ -0,0 +1,353 @@
+//! `VITE_*` 配置的解析与校验
+//!
+//! 由 `build.rs` 通过 `#[path]` 引入；库中只在测试时编译，以便运行下面的单元测试。
+//! 与 [`crate::parsing`] 一样只能使用标准库。
+
+use std::collections::BTreeMap;
+
+use crate::parsing::{edit_distance, parse_flags};
+
+/// 必填的配置键
+pub const REQUIRED_KEYS: &[&str] = &["VITE_BASE_URL", "VITE_APP_TITLE", "VITE_ENV"];
+
+/// 可选的配置键
+pub const OPTIONAL_KEYS: &[&str] = &[
+    "VITE_FEATURE_FLAGS",
+    "VITE_LOG_FILTER",
+    "VITE_ROUTER_MODE",
+    "VITE_ERROR_ENDPOINT",
+];
+
+/// 日志过滤规则中允许的级别
+pub const LOG_LEVELS: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+
+/// `VITE_ROUTER_MODE` 的取值与对应的 `RouterMode` 变体
+pub const ROUTER_MODES: &[(&str, &str)] = &[("history", "History"), ("hash", "Hash")];
+
+/// 环境名称与对应的 `.env` 文件、`AppEnvironment` 变体
+pub const ENVIRONMENTS: &[(&str, &str, &str)] = &[
+    ("development", ".env.development", "Development"),
+    ("production", ".env.production", "Production"),
+    ("github-pages", ".env.github", "GithubPages"),
+];
+
+/// 解析单个 `.env` 文件的内容，规则与 `parseEnvFile` 相同
+///
+/// `file` 只用于错误信息中的位置。
+pub fn parse_env_file(
+    file: &str,
+    content: &str,
+    values: &mut BTreeMap<String, String>,
+    errors: &mut Vec<String>,
+) {
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            errors.push(format!(
+                "{file}:{}: expected KEY=VALUE, found {line:?}",
+                index + 1
+            ));
+            continue;
+        };
+        let key = key.trim();
+        let value = strip_quotes(value.trim());
+
+        if key.starts_with("VITE_") && !known_keys().any(|known| known == key) {
+            errors.push(format!(
+                "{file}:{}: unknown key {key}{}",
+                index + 1,
+                suggest(key)
+                    .map(|k| format!(" (did you mean {k}?)"))
+                    .unwrap_or_default()
+            ));
+            continue;
+        }
+
+        values.insert(key.to_string(), value.to_string());
+    }
+}
+
+fn strip_quotes(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+/// 校验必填键与取值格式
+pub fn validate(values: &BTreeMap<String, String>, errors: &mut Vec<String>) {
+    for key in REQUIRED_KEYS {
+        if !values.contains_key(*key) {
+            errors.push(format!("missing required key {key}"));
+        }
+    }
+
+    if let Some(base_url) = values.get("VITE_BASE_URL") {
+        if !base_url.starts_with('/') || !base_url.ends_with('/') {
+            errors.push(format!(
+                "VITE_BASE_URL={base_url:?} must start and end with '/'"
+            ));
+        }
+        if base_url.contains(char::is_whitespace) {
+            errors.push(format!(
+                "VITE_BASE_URL={base_url:?} must not contain whitespace"
+            ));
+        }
+    }
+
+    if let Some(title) = values.get("VITE_APP_TITLE") {
+        if title.trim().is_empty() {
+            errors.push("VITE_APP_TITLE must not be empty".to_string());
+        }
+    }
+
+    if let Some(name) = values.get("VITE_ENV") {
+        if !ENVIRONMENTS.iter().any(|(env, ..)| env == name) {
+            errors.push(format!(
+                "VITE_ENV={name:?} is not a known environment (expected one of: {})",
+                environment_names()
+            ));
+        }
+    }
+
+    if let Some(flags) = values.get("VITE_FEATURE_FLAGS") {
+        for (name, _) in parse_flags(flags) {
+            let valid = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+            if !valid {
+                errors.push(format!(
+                    "VITE_FEATURE_FLAGS contains invalid flag name {name:?} \
+                     (use lowercase letters, digits and '-')"
+                ));
+            }
+        }
+    }
+
+    if let Some(spec) = values.get("VITE_LOG_FILTER") {
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let level = directive
+                .split_once('=')
+                .map_or(directive, |(_, level)| level)
+                .trim();
+            if !LOG_LEVELS.contains(&level.to_ascii_lowercase().as_str()) {
+                errors.push(format!(
+                    "VITE_LOG_FILTER directive {directive:?} has invalid level {level:?} \
+                     (expected one of: {})",
+                    LOG_LEVELS.join(", ")
+                ));
+            }
+        }
+    }
+
+    if let Some(endpoint) = values.get("VITE_ERROR_ENDPOINT") {
+        let valid = ["http://", "https://", "/"]
+            .iter()
+            .any(|prefix| endpoint.starts_with(prefix));
+        if !valid || endpoint.contains(char::is_whitespace) {
+            errors.push(format!(
+                "VITE_ERROR_ENDPOINT={endpoint:?} must be an http(s) URL or an absolute path"
+            ));
+        }
+    }
+
+    if let Some(mode) = values.get("VITE_ROUTER_MODE") {
+        if !ROUTER_MODES.iter().any(|(name, _)| name == mode) {
+            errors.push(format!(
+                "VITE_ROUTER_MODE={mode:?} is not a known router mode (expected one of: {})",
+                ROUTER_MODES
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+}
+
+pub fn known_keys() -> impl Iterator<Item = &'static str> {
+    REQUIRED_KEYS.iter().chain(OPTIONAL_KEYS).copied()
+}
+
+fn environment_names() -> String {
+    ENVIRONMENTS
+        .iter()
+        .map(|(name, ..)| *name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 为拼写错误的键寻找最接近的已知键
+fn suggest(key: &str) -> Option<&'static str> {
+    known_keys()
+        .map(|known| (known, edit_distance(key, known)))
+        .filter(|(_, distance)| *distance <= 3)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(known, _)| known)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn valid() -> BTreeMap<String, String> {
+        values(&[
+            ("VITE_BASE_URL", "/app/"),
+            ("VITE_APP_TITLE", "Demo"),
+            ("VITE_ENV", "production"),
+        ])
+    }
+
+    fn errors_for(overrides: &[(&str, &str)]) -> Vec<String> {
+        let mut config = valid();
+        config.extend(values(overrides));
+        let mut errors = Vec::new();
+        validate(&config, &mut errors);
+        errors
+    }
+
+    #[test]
+    fn accepts_complete_config() {
+        let errors = errors_for(&[
+            ("VITE_FEATURE_FLAGS", "beta,!legacy-nav,v2"),
+            ("VITE_LOG_FILTER", "warn,leptos_vite_template::routes=DEBUG"),
+            ("VITE_ROUTER_MODE", "hash"),
+            ("VITE_ERROR_ENDPOINT", "https://errors.example.com/report"),
+        ]);
+        assert_eq!(errors, Vec::<String>::new());
+    }
+
+    #[test]
+    fn reports_missing_required_keys() {
+        let mut errors = Vec::new();
+        validate(&values(&[("VITE_ENV", "development")]), &mut errors);
+        assert_eq!(
+            errors,
+            [
+                "missing required key VITE_BASE_URL",
+                "missing required key VITE_APP_TITLE",
+            ]
+        );
+    }
+
+    #[test]
+    fn base_url_needs_slashes_and_no_whitespace() {
+        assert_eq!(errors_for(&[("VITE_BASE_URL", "app/")]).len(), 1);
+        assert_eq!(errors_for(&[("VITE_BASE_URL", "/app")]).len(), 1);
+        assert_eq!(errors_for(&[("VITE_BASE_URL", "/my app/")]).len(), 1);
+        assert!(errors_for(&[("VITE_BASE_URL", "/")]).is_empty());
+    }
+
+    #[test]
+    fn rejects_blank_title() {
+        assert_eq!(
+            errors_for(&[("VITE_APP_TITLE", "  ")]),
+            ["VITE_APP_TITLE must not be empty"]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_environment() {
+        let errors = errors_for(&[("VITE_ENV", "staging")]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("development, production, github-pages"));
+    }
+
+    #[test]
+    fn rejects_invalid_flag_names() {
+        let errors = errors_for(&[("VITE_FEATURE_FLAGS", "ok,Bad,!also_bad,!")]);
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("\"Bad\""));
+        assert!(errors[1].contains("\"also_bad\""));
+        assert!(errors[2].contains("\"\""));
+    }
+
+    #[test]
+    fn rejects_unknown_log_levels() {
+        let errors = errors_for(&[("VITE_LOG_FILTER", "info,routes=verbose")]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("\"verbose\""));
+    }
+
+    #[test]
+    fn error_endpoint_must_be_url_or_absolute_path() {
+        assert!(errors_for(&[("VITE_ERROR_ENDPOINT", "/api/errors")]).is_empty());
+        assert_eq!(
+            errors_for(&[("VITE_ERROR_ENDPOINT", "api/errors")]).len(),
+            1
+        );
+        assert_eq!(
+            errors_for(&[("VITE_ERROR_ENDPOINT", "ftp://example.com")]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_router_mode() {
+        let errors = errors_for(&[("VITE_ROUTER_MODE", "memory")]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("history, hash"));
+    }
+
+    #[test]
+    fn parses_env_file_lines() {
+        let content = "\
+# comment
+VITE_APP_TITLE = \"Quoted Title\"
+
+VITE_BASE_URL='/base/'
+OTHER_KEY=kept
+";
+        let mut config = BTreeMap::new();
+        let mut errors = Vec::new();
+        parse_env_file(".env", content, &mut config, &mut errors);
+        assert!(errors.is_empty());
+        assert_eq!(
+            config,
+            values(&[
+                ("VITE_APP_TITLE", "Quoted Title"),
+                ("VITE_BASE_URL", "/base/"),
+                ("OTHER_KEY", "kept"),
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_malformed_lines_and_unknown_keys() {
+        let content = "VITE_BASE_URL=/\nnot a pair\nVITE_BASE_ULR=/x/\nVITE_SOMETHING_ELSE=1\n";
+        let mut config = BTreeMap::new();
+        let mut errors = Vec::new();
+        parse_env_file(".env.production", content, &mut config, &mut errors);
+        assert_eq!(config, values(&[("VITE_BASE_URL", "/")]));
+        assert_eq!(
+            errors,
+            [
+                ".env.production:2: expected KEY=VALUE, found \"not a pair\"",
+                ".env.production:3: unknown key VITE_BASE_ULR (did you mean VITE_BASE_URL?)",
+                ".env.production:4: unknown key VITE_SOMETHING_ELSE",
+            ]
+        );
+    }
+
+    #[test]
+    fn suggests_closest_known_key() {
+        assert_eq!(suggest("VITE_APP_TITEL"), Some("VITE_APP_TITLE"));
+        assert_eq!(suggest("VITE_LOG_FILTR"), Some("VITE_LOG_FILTER"));
+        assert_eq!(suggest("VITE_ROUTERMODE"), Some("VITE_ROUTER_MODE"));
+        assert_eq!(suggest("VITE_SOMETHING_ELSE"), None);
+    }
+}
@@ -0,0 +1,224 @@
+//! 崩溃处理
+//!
+//! wasm 中的 panic 无法恢复：`panic = "abort"` 时实例在 panic hook 返回后即终止，之后任何回调都不会再执行。
+//! 因此 [`install_panic_hook`] 在 hook 中直接用 DOM 接口把所有已挂载的应用实例替换为崩溃页面，
+//! 重新加载按钮也只依赖内联的 `location.reload()`，不回调 wasm。
+//!
+//! 可恢复的错误（`Result::Err`）由 `App` 根部的 `<ErrorBoundary>` 显示，见 `components::error_fallback`。
+
+use std::cell::RefCell;
+use std::panic::PanicHookInfo;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use wasm_bindgen::JsValue;
+use web_sys::{Document, Element};
+
+use crate::config;
+use crate::env::AppEnvironment;
+use crate::reporting::{self, ReportKind};
+
+/// 默认挂载点的 id，与 `index.html` 一致
+pub const MOUNT_ID: &str = "leptos-app";
+
+thread_local! {
+    /// 当前挂载的应用实例的根元素与运行时配置中的部署环境
+    static ROOTS: RefCell<Vec<(Element, AppEnvironment)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// 登记应用实例的根元素，崩溃时替换其中的内容
+///
+/// `env` 来自实例的运行时配置，决定崩溃页面是否显示 panic 详情。
+pub fn register_root(root: Element, env: AppEnvironment) {
+    ROOTS.with_borrow_mut(|roots| roots.push((root, env)));
+}
+
+/// 实例卸载后取消登记
+pub fn unregister_root(root: &Element) {
+    ROOTS.with_borrow_mut(|roots| roots.retain(|(registered, _)| registered != root));
+}
+
+/// 同一实例只显示一次崩溃页面，避免 hook 中再次 panic 时反复替换
+static CRASHED: AtomicBool = AtomicBool::new(false);
+
+/// 生成错误编号，用户反馈问题时可以据此在日志中定位
+///
+/// 由毫秒时间戳与随机数组成，例如 `MFX3K2A1-7Q4Z`。
+pub fn error_id() -> String {
+    let timestamp = js_sys::Date::now() as u64;
+    let random = (js_sys::Math::random() * 36f64.powi(4)) as u64;
+    format!("{}-{:0>4}", to_base36(timestamp), to_base36(random))
+}
+
+fn to_base36(mut value: u64) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut out = Vec::new();
+    loop {
+        out.push(DIGITS[(value % 36) as usize]);
+        value /= 36;
+        if value == 0 {
+            break;
+        }
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap_or_default()
+}
+
+/// 安装 panic hook：先输出到控制台并上报，再显示崩溃页面
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+
+        if CRASHED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let id = error_id();
+        web_sys::console::error_1(&JsValue::from_str(&format!("crash id: {id}")));
+
+        let message = panic_message(info);
+        let location = info.location().map(|location| {
+            format!(
+                "{}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            )
+        });
+        reporting::report(
+            id.clone(),
+            ReportKind::Panic,
+            message.clone(),
+            location.clone(),
+        );
+
+        let details = match location {
+            Some(location) => format!("{message}\n  at {location}"),
+            None => message,
+        };
+        if let Err(err) = show_crash_screen(&id, &details) {
+            web_sys::console::error_2(&JsValue::from_str("failed to show crash screen:"), &err);
+        }
+    }));
+}
+
+/// panic 消息
+fn panic_message(info: &PanicHookInfo<'_>) -> String {
+    info.payload()
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+/// 用崩溃页面替换所有实例的内容，没有登记的实例时使用默认挂载点
+///
+/// 详细信息只在实例的运行时环境为开发环境时显示；尚未挂载任何实例时按编译时配置判断。
+fn show_crash_screen(id: &str, details: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let mut roots = ROOTS
+        .try_with(|roots| {
+            roots
+                .try_borrow()
+                .map(|roots| roots.clone())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+    if roots.is_empty() {
+        let root = match document.get_element_by_id(MOUNT_ID) {
+            Some(root) => root,
+            None => document
+                .body()
+                .ok_or_else(|| JsValue::from_str("no body"))?
+                .into(),
+        };
+        roots.push((root, config::ENV));
+    }
+
+    for (root, env) in roots {
+        let details = env.shows_debug_info().then_some(details);
+        let screen = crash_screen(&document, id, details)?;
+        root.set_inner_html("");
+        root.append_child(&screen)?;
+    }
+    Ok(())
+}
+
+/// 崩溃页面的 DOM
+fn crash_screen(document: &Document, id: &str, details: Option<&str>) -> Result<Element, JsValue> {
+    let screen = element(
+        document,
+        "div",
+        "max-width: 560px; margin: 80px auto; padding: 32px; text-align: center; \
+         font-family: sans-serif; border-radius: 12px; background: #fff; \
+         box-shadow: 0 2px 12px rgba(0,0,0,0.12);",
+    )?;
+    screen.set_attribute("class", "crash-screen")?;
+    screen.set_attribute("role", "alert")?;
+
+    let title = element(document, "h1", "margin: 0 0 12px 0; color: #333;")?;
+    title.set_text_content(Some("😵 应用出现了问题"));
+    screen.append_child(&title)?;
+
+    let hint = element(document, "p", "color: #666;")?;
+    hint.set_text_content(Some(
+        "请重新加载页面。如果问题持续出现，请在反馈时附上下面的错误编号。",
+    ));
+    screen.append_child(&hint)?;
+
+    let code = element(
+        document,
+        "p",
+        "font-family: monospace; font-size: 18px; color: #c0392b;",
+    )?;
+    code.set_text_content(Some(&format!("错误编号：{id}")));
+    screen.append_child(&code)?;
+
+    if let Some(details) = details {
+        let pre = element(
+            document,
+            "pre",
+            "text-align: left; white-space: pre-wrap; word-break: break-all; \
+             background: #f8f9fa; padding: 12px; border-radius: 6px; font-size: 13px;",
+        )?;
+        pre.set_text_content(Some(details));
+        screen.append_child(&pre)?;
+    }
+
+    let reload = element(
+        document,
+        "button",
+        "padding: 8px 24px; font-size: 16px; cursor: pointer;",
+    )?;
+    reload.set_text_content(Some("重新加载"));
+    // wasm 实例已终止，只能使用内联脚本
+    reload.set_attribute("onclick", "location.reload()")?;
+    screen.append_child(&reload)?;
+
+    Ok(screen)
+}
+
+fn element(document: &Document, tag: &str, style: &str) -> Result<Element, JsValue> {
+    let element = document.create_element(tag)?;
+    element.set_attribute("style", style)?;
+    Ok(element)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_base36_uses_uppercase_digits() {
+        assert_eq!(to_base36(0), "0");
+        assert_eq!(to_base36(35), "Z");
+        assert_eq!(to_base36(36), "10");
+        assert_eq!(to_base36(1_295), "ZZ");
+    }
+
+    #[test]
+    fn to_base36_handles_timestamps_and_max() {
+        assert_eq!(to_base36(1_700_000_000_000), "LOYW3V28");
+        assert_eq!(to_base36(u64::MAX), "3W5E11264SGSF");
+    }
+}
@@ -0,0 +1,64 @@
+//! 调试辅助
+//!
+//! Leptos 没有公开遍历 Owner 树的接口，因此由路由视图等位置调用 [`track_owner`]，
+//! 沿父级链登记当前 Owner 及其全部祖先。Effect 重新运行时也会清理 Owner，所以只有在 Owner
+//! 真正被释放后才注销，切换路由后没有被释放的 Owner 会一直计入。release 构建中这些函数都是空实现。
+
+#[cfg(debug_assertions)]
+use std::collections::HashSet;
+#[cfg(debug_assertions)]
+use std::sync::{LazyLock, Mutex};
+
+#[cfg(debug_assertions)]
+use leptos::prelude::*;
+
+/// 已登记且仍存活的 Owner，以 [`Owner::debug_id`] 区分
+#[cfg(debug_assertions)]
+static LIVE_OWNERS: LazyLock<Mutex<HashSet<usize>>> = LazyLock::new(Mutex::default);
+
+#[cfg(debug_assertions)]
+static OWNERS_CHANGED: LazyLock<ArcTrigger> = LazyLock::new(ArcTrigger::new);
+
+/// 登记当前的响应式 Owner 及其祖先，直到它们被释放
+#[cfg(debug_assertions)]
+pub fn track_owner() {
+    let mut next = Owner::current();
+    while let Some(owner) = next {
+        // 已登记的 Owner 的祖先也都已登记
+        if !LIVE_OWNERS.lock().unwrap().insert(owner.debug_id()) {
+            break;
+        }
+        watch(&owner);
+        next = owner.parent();
+    }
+    OWNERS_CHANGED.notify();
+}
+
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub fn track_owner() {}
+
+/// 在 Owner 上注册清理函数：仍能升级说明只是重新运行或即将释放，继续监视；否则注销
+#[cfg(debug_assertions)]
+fn watch(owner: &Owner) {
+    let id = owner.debug_id();
+    let weak = owner.downgrade();
+    owner.with(|| {
+        on_cleanup(move || {
+            match weak.upgrade() {
+                Some(owner) => watch(&owner),
+                None => {
+                    LIVE_OWNERS.lock().unwrap().remove(&id);
+                }
+            }
+            OWNERS_CHANGED.notify();
+        })
+    });
+}
+
+/// 当前存活的已登记 Owner 数量，在响应式上下文中调用时会追踪变化
+#[cfg(debug_assertions)]
+pub fn live_owners() -> usize {
+    OWNERS_CHANGED.track();
+    LIVE_OWNERS.lock().unwrap().len()
+}
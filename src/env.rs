@@ -1,35 +1,273 @@
-/// 简单的环境变量获取模块
+//! 环境配置模块
+//!
+//! 编译时配置由 `build.rs` 生成（见 [`crate::config`]），运行时配置在启动时从
+//! `public/config.json` 加载，这样同一份 `pkg/` 产物可以在不同部署环境之间直接复用。
 
-/// 获取应用的基础路径
-/// 
-/// 编译时从环境变量读取，如果不存在则返回默认值 "/"
-pub fn get_base_url() -> String {
-    option_env!("VITE_BASE_URL").unwrap_or("/").to_string()
-}
+use std::fmt;
 
-/// 获取应用标题
-/// 
-/// 编译时从环境变量读取，如果不存在则返回默认标题
-pub fn get_app_title() -> String {
-    option_env!("VITE_APP_TITLE").unwrap_or("Leptos + Vite Demo").to_string()
-}
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Response;
 
-/// 获取环境类型字符串
-/// 
-/// 编译时从环境变量读取，如果不存在则返回 "development"
-pub fn get_env() -> String {
-    option_env!("VITE_ENV").unwrap_or("development").to_string()
-}
+use crate::config;
+use crate::logging::LogFilter;
+use crate::transitions::TransitionConfig;
+
+/// 运行时配置文件路径（相对于 `<base href>` 解析）
+pub const CONFIG_PATH: &str = "config.json";
+
+/// 当前构建的元数据
+pub const BUILD_INFO: BuildInfo = BuildInfo {
+    version: env!("CARGO_PKG_VERSION"),
+    git_sha: config::GIT_SHA,
+    git_dirty: config::GIT_DIRTY,
+    timestamp: config::BUILD_TIMESTAMP,
+};
 
 /// 获取调试信息字符串
-pub fn get_debug_info() -> String {
+pub fn get_debug_info(config: &AppConfig) -> String {
     format!(
         "Environment Info:\n\
          - Base URL: {}\n\
          - Environment: {}\n\
-         - App Title: {}",
-        get_base_url(),
-        get_env(),
-        get_app_title()
+         - App Title: {}\n\
+         - Router Mode: {}\n\
+         - Build: {}",
+        config.base_url,
+        config.env,
+        config.app_title,
+        config.router_mode,
+        BUILD_INFO
     )
-}
\ No newline at end of file
+}
+
+/// 构建元数据
+///
+/// 由 `build.rs` 在编译时采集，用于在问题反馈中精确定位构建版本。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildInfo {
+    /// crate 版本号
+    pub version: &'static str,
+    /// git 提交哈希，不在 git 仓库中构建时为 `None`
+    pub git_sha: Option<&'static str>,
+    /// 构建时工作区是否有未提交的修改
+    pub git_dirty: bool,
+    /// 构建时间（Unix 秒）
+    pub timestamp: u64,
+}
+
+impl BuildInfo {
+    /// 7 位短提交哈希，工作区有修改时追加 `-dirty`
+    pub fn short_sha(&self) -> String {
+        match self.git_sha {
+            Some(sha) => {
+                let short = &sha[..sha.len().min(7)];
+                if self.git_dirty {
+                    format!("{}-dirty", short)
+                } else {
+                    short.to_string()
+                }
+            }
+            None => "unknown".to_string(),
+        }
+    }
+
+    /// ISO 8601 格式的构建时间
+    pub fn build_time(&self) -> String {
+        let millis = self.timestamp as f64 * 1000.0;
+        js_sys::Date::new(&millis.into()).to_iso_string().into()
+    }
+}
+
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{} ({}, built {})", self.version, self.short_sha(), self.build_time())
+    }
+}
+
+/// 部署环境
+///
+/// 与 `scripts/env-loader.js` 支持的环境一一对应。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppEnvironment {
+    Development,
+    Production,
+    GithubPages,
+}
+
+impl AppEnvironment {
+    /// 环境名称，与 `VITE_ENV` 的取值一致
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Production => "production",
+            Self::GithubPages => "github-pages",
+        }
+    }
+
+    /// 默认日志级别，可以被 `VITE_LOG_FILTER` 或 `config.json` 中的 `logFilter` 覆盖
+    pub fn log_level(self) -> log::Level {
+        match self {
+            Self::Development => log::Level::Debug,
+            Self::Production | Self::GithubPages => log::Level::Warn,
+        }
+    }
+
+    /// 是否在启动时输出调试信息
+    pub fn shows_debug_info(self) -> bool {
+        self == Self::Development
+    }
+
+    /// 是否注册演示页面路由
+    pub fn registers_demo_routes(self) -> bool {
+        matches!(self, Self::Development | Self::GithubPages)
+    }
+
+    /// 是否挂载开发者工具
+    pub fn mounts_dev_tools(self) -> bool {
+        self == Self::Development
+    }
+}
+
+impl fmt::Display for AppEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 路由模式
+///
+/// `Hash` 模式下地址栏显示为 `/#/md-demo`，适合无法把任意路径回退到 `index.html` 的静态托管；
+/// 应用内部（包括 `<A>` 生成的链接）始终使用普通路径，两种模式下行为一致。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RouterMode {
+    #[default]
+    History,
+    Hash,
+}
+
+impl RouterMode {
+    /// 模式名称，与 `VITE_ROUTER_MODE` 的取值一致
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::History => "history",
+            Self::Hash => "hash",
+        }
+    }
+}
+
+impl fmt::Display for RouterMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 应用运行时配置
+///
+/// 字段名与 `config.json` 中的 camelCase 键对应，缺失的字段回退到编译时的值。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AppConfig {
+    pub base_url: String,
+    pub app_title: String,
+    pub env: AppEnvironment,
+    /// 日志过滤规则，语法见 [`LogFilter`]
+    pub log_filter: Option<String>,
+    /// 路由模式
+    pub router_mode: RouterMode,
+    /// 路由切换动画
+    pub transitions: TransitionConfig,
+    /// 错误上报地址，`None` 时不上报，见 [`crate::reporting`]
+    pub error_endpoint: Option<String>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            base_url: config::BASE_URL.to_string(),
+            app_title: config::APP_TITLE.to_string(),
+            env: config::ENV,
+            log_filter: config::LOG_FILTER.map(str::to_string),
+            router_mode: config::ROUTER_MODE,
+            transitions: TransitionConfig::default(),
+            error_endpoint: config::ERROR_ENDPOINT.map(str::to_string),
+        }
+    }
+}
+
+impl AppConfig {
+    /// 加载运行时配置
+    ///
+    /// 依次合并编译时配置、`url` 指向的配置文件（`None` 时跳过）与 `overrides` 中的字段。
+    /// 文件缺失或不是合法 JSON 对象时记录警告并忽略；合并后字段取值无效（例如未知的 `env`）时返回错误，
+    /// 避免带着错误配置运行。
+    pub async fn load(
+        url: Option<&str>,
+        overrides: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<Self, String> {
+        let mut fields = match url {
+            Some(url) => match Self::fetch(url).await {
+                Ok(serde_json::Value::Object(fields)) => fields,
+                Ok(_) => {
+                    log::warn!("Ignoring {}: not a JSON object", url);
+                    serde_json::Map::new()
+                }
+                Err(e) => {
+                    log::warn!("Failed to load {}, using build-time config: {:?}", url, e);
+                    serde_json::Map::new()
+                }
+            },
+            None => serde_json::Map::new(),
+        };
+        fields.extend(overrides);
+
+        let config = serde_json::from_value(serde_json::Value::Object(fields))
+            .map_err(|e| format!("Invalid app config: {}", e))?;
+        log::debug!("Runtime config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    /// 当前配置对应的日志过滤规则
+    ///
+    /// 未配置或规则无效时使用环境的默认级别。
+    pub fn log_filter(&self) -> LogFilter {
+        let default = self.env.log_level().to_level_filter();
+        match self.log_filter.as_deref().map(|spec| LogFilter::parse(spec, default)) {
+            Some(Ok(filter)) => filter,
+            Some(Err(e)) => {
+                log::warn!("Ignoring invalid log filter: {}", e);
+                LogFilter::new(default)
+            }
+            None => LogFilter::new(default),
+        }
+    }
+
+    async fn fetch(url: &str) -> Result<serde_json::Value, JsValue> {
+        let window = web_sys::window().ok_or("window is not available")?;
+        let response: Response = JsFuture::from(window.fetch_with_str(url)).await?.dyn_into()?;
+
+        if !response.ok() {
+            return Err(format!("HTTP {}", response.status()).into());
+        }
+
+        let text = JsFuture::from(response.text()?).await?.as_string().unwrap_or_default();
+        serde_json::from_str(&text).map_err(|e| e.to_string().into())
+    }
+}
+
+/// 将运行时配置注入到当前 Owner 的上下文中
+pub fn provide_app_config(config: AppConfig) {
+    provide_context(RwSignal::new(config));
+}
+
+/// 获取运行时配置信号
+///
+/// 必须在 [`provide_app_config`] 之后的组件树中调用。
+pub fn use_app_config() -> RwSignal<AppConfig> {
+    expect_context::<RwSignal<AppConfig>>()
+}
@@ -0,0 +1,135 @@
+//! 功能开关
+//!
+//! 默认值来自编译时配置 `VITE_FEATURE_FLAGS`，可以被 localStorage 与 URL 查询参数
+//! `?ff=name,!other` 覆盖，优先级为 URL > localStorage > 默认值。
+//! URL 中的覆盖会写回 localStorage，因此刷新或站内跳转后依然生效。
+
+use std::collections::{BTreeSet, HashMap};
+
+use leptos::prelude::*;
+use web_sys::{Storage, UrlSearchParams};
+
+use crate::config;
+use crate::parsing::parse_flags;
+
+/// localStorage 中保存覆盖值的键
+const STORAGE_KEY: &str = "feature-flags";
+
+/// 用于覆盖开关的查询参数名
+const QUERY_PARAM: &str = "ff";
+
+/// 功能开关服务
+///
+/// 通过上下文共享，所有读取方法都会被响应式追踪。
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureFlags {
+    overrides: RwSignal<HashMap<String, bool>>,
+}
+
+impl FeatureFlags {
+    /// 合并 localStorage 与 URL 中的覆盖值
+    fn new() -> Self {
+        let mut overrides: HashMap<String, bool> = storage()
+            .and_then(|s| s.get_item(STORAGE_KEY).ok().flatten())
+            .map(|stored| owned_flags(&stored).collect())
+            .unwrap_or_default();
+
+        if let Some(query) = query_overrides() {
+            log::debug!("Feature flag overrides from URL: {}", query);
+            overrides.extend(owned_flags(&query));
+            persist(&overrides);
+        }
+
+        Self {
+            overrides: RwSignal::new(overrides),
+        }
+    }
+
+    /// 开关是否开启，未声明的开关视为关闭
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.overrides
+            .with(|overrides| overrides.get(name).copied())
+            .or_else(|| default_value(name))
+            .unwrap_or(false)
+    }
+
+    /// 获取单个开关的响应式信号
+    pub fn signal(&self, name: impl Into<String>) -> Signal<bool> {
+        let this = *self;
+        let name = name.into();
+        Signal::derive(move || this.is_enabled(&name))
+    }
+
+    /// 在运行时覆盖开关并持久化到 localStorage
+    pub fn set(&self, name: &str, enabled: bool) {
+        self.overrides.update(|overrides| {
+            overrides.insert(name.to_string(), enabled);
+            persist(overrides);
+        });
+    }
+
+    /// 清除所有覆盖值，恢复为编译时默认值
+    pub fn reset(&self) {
+        self.overrides.set(HashMap::new());
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(STORAGE_KEY);
+        }
+    }
+
+    /// 所有已知开关及其当前状态（默认值与覆盖值的并集）
+    pub fn all(&self) -> Vec<(String, bool)> {
+        let mut names: BTreeSet<String> =
+            config::FEATURE_FLAGS.iter().map(|(name, _)| name.to_string()).collect();
+        self.overrides.with(|overrides| names.extend(overrides.keys().cloned()));
+        names
+            .into_iter()
+            .map(|name| {
+                let enabled = self.is_enabled(&name);
+                (name, enabled)
+            })
+            .collect()
+    }
+}
+
+/// 解析 `name,!other` 格式的开关列表，见 [`parse_flags`]
+fn owned_flags(input: &str) -> impl Iterator<Item = (String, bool)> + '_ {
+    parse_flags(input).map(|(name, enabled)| (name.to_string(), enabled))
+}
+
+fn default_value(name: &str) -> Option<bool> {
+    config::FEATURE_FLAGS
+        .iter()
+        .find(|(flag, _)| *flag == name)
+        .map(|(_, enabled)| *enabled)
+}
+
+fn query_overrides() -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()?.get(QUERY_PARAM)
+}
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+fn persist(overrides: &HashMap<String, bool>) {
+    let Some(storage) = storage() else {
+        return;
+    };
+    let value = overrides
+        .iter()
+        .map(|(name, enabled)| if *enabled { name.clone() } else { format!("!{}", name) })
+        .collect::<Vec<_>>()
+        .join(",");
+    let _ = storage.set_item(STORAGE_KEY, &value);
+}
+
+/// 创建功能开关服务并注入上下文
+pub fn provide_feature_flags() {
+    provide_context(FeatureFlags::new());
+}
+
+/// 获取功能开关服务
+pub fn use_feature_flags() -> FeatureFlags {
+    expect_context::<FeatureFlags>()
+}
@@ -0,0 +1,76 @@
+//! Hash 路由模式
+//!
+//! leptos_router 只支持基于 `history` 的路径路由，并直接读取 `window.location`。
+//! 这里在挂载前安装一层地址栏镜像：路由器看到的始终是 `{base}md-demo` 这样的路径，
+//! 而地址栏在每次导航后被改写为 `{base}#/md-demo`。这样静态托管只需要提供 `index.html`，
+//! 应用内部的链接与导航逻辑在两种模式下完全相同。
+//!
+//! 1. 启动时把 `#/` 形式的地址转换为路径形式，供路由器读取初始位置；
+//! 2. 包装 `history.pushState` / `replaceState`，在路由器写入路径后于微任务中改写为 hash 形式；
+//! 3. 先于路由器注册 `popstate` 监听，前进/后退时同步还原为路径形式再交给路由器处理。
+
+use std::sync::OnceLock;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(inline_js = r#"
+export function install_hash_history(base) {
+    const root = base.replace(/\/$/, '');
+    const replaceState = history.replaceState.bind(history);
+    const pushState = history.pushState.bind(history);
+
+    const isHashForm = () =>
+        location.hash.startsWith('#/') && (location.pathname === base || location.pathname === root);
+
+    const toPath = () => {
+        if (isHashForm()) {
+            replaceState(history.state, '', base + location.hash.slice(2));
+        }
+    };
+
+    const toHash = () => {
+        if (isHashForm() || !location.pathname.startsWith(base)) {
+            return;
+        }
+        const rest = location.pathname.slice(base.length) + location.search + location.hash;
+        replaceState(history.state, '', base + '#/' + rest);
+    };
+
+    history.pushState = (...args) => {
+        pushState(...args);
+        queueMicrotask(toHash);
+    };
+    history.replaceState = (...args) => {
+        replaceState(...args);
+        queueMicrotask(toHash);
+    };
+    window.addEventListener('popstate', () => {
+        toPath();
+        queueMicrotask(toHash);
+    });
+
+    toPath();
+    queueMicrotask(toHash);
+}
+"#)]
+extern "C" {
+    fn install_hash_history(base: &str);
+}
+
+/// 已安装镜像的基础路径，地址栏只有一个，同一页面中只能安装一次
+static INSTALLED: OnceLock<String> = OnceLock::new();
+
+/// 安装 hash 模式的地址栏镜像
+///
+/// 必须在挂载 `Router` 之前调用，保证 `popstate` 监听先于路由器执行。重复调用时忽略。
+pub fn install(base_url: &str) {
+    let installed = INSTALLED.get_or_init(|| {
+        install_hash_history(base_url);
+        base_url.to_string()
+    });
+    if installed != base_url {
+        log::warn!(
+            "hash router mode is already installed for {installed:?}, ignoring base {base_url:?}"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! 热重载时保留状态
+//!
+//! 开发时 `vite.config.ts` 监视 `pkg/`，wasm 重新构建后整页重新加载，所有信号都会回到初始值。
+//! 用 [`hot_signal`] 代替 `RwSignal::new` 创建的信号会在页面卸载前保存到 sessionStorage，
+//! 重新加载后第一次以相同的键创建时恢复。
+//!
+//! 只在 debug 构建中生效，release 构建中 [`hot_signal`] 就是 `RwSignal::new`。
+//! 键在整个应用中需要唯一，建议以页面名作为前缀，例如 `"mdui-demo.count"`。
+
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// 创建一个在热重载后保留值的信号
+///
+/// 保存的值无法反序列化为 `T`（例如修改了类型）时使用 `default`。
+#[cfg(debug_assertions)]
+pub fn hot_signal<T>(key: &'static str, default: T) -> RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    dev::hot_signal(key, default)
+}
+
+/// 创建一个在热重载后保留值的信号
+///
+/// release 构建中不保存状态，等同于 `RwSignal::new(default)`。
+#[cfg(not(debug_assertions))]
+pub fn hot_signal<T>(_key: &'static str, default: T) -> RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    RwSignal::new(default)
+}
+
+#[cfg(debug_assertions)]
+mod dev {
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, Mutex};
+
+    use leptos::ev;
+    use leptos::prelude::*;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::Value;
+    use web_sys::Storage;
+
+    /// sessionStorage 中保存快照的键
+    const STORAGE_KEY: &str = "hot-state";
+
+    static HOT_STATE: LazyLock<Mutex<HotState>> = LazyLock::new(|| Mutex::new(HotState::load()));
+
+    type Snapshot = Box<dyn Fn() -> Option<Value> + Send + Sync>;
+
+    struct HotState {
+        /// 上次卸载前保存、尚未被恢复的值
+        restored: HashMap<String, Value>,
+        /// 当前存在的信号
+        signals: HashMap<&'static str, (u64, Snapshot)>,
+        next_id: u64,
+    }
+
+    impl HotState {
+        /// 读取并清除上次的快照，注册卸载前保存的监听
+        fn load() -> Self {
+            let restored = storage()
+                .and_then(|storage| {
+                    let json = storage.get_item(STORAGE_KEY).ok().flatten();
+                    let _ = storage.remove_item(STORAGE_KEY);
+                    json
+                })
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default();
+
+            let _ = window_event_listener(ev::beforeunload, |_| save());
+
+            Self {
+                restored,
+                signals: HashMap::new(),
+                next_id: 0,
+            }
+        }
+    }
+
+    pub fn hot_signal<T>(key: &'static str, default: T) -> RwSignal<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        let mut state = HOT_STATE.lock().unwrap();
+
+        let initial = match state.restored.remove(key) {
+            Some(value) => serde_json::from_value(value).unwrap_or_else(|err| {
+                log::warn!("discarding hot state {key:?}: {err}");
+                default
+            }),
+            None => default,
+        };
+        let signal = RwSignal::new(initial);
+
+        if state.signals.contains_key(key) {
+            log::warn!("hot_signal key {key:?} is already in use, the newer signal wins");
+        }
+        let id = state.next_id;
+        state.next_id += 1;
+        state.signals.insert(
+            key,
+            (
+                id,
+                Box::new(move || {
+                    signal
+                        .try_with_untracked(|value| serde_json::to_value(value).ok())
+                        .flatten()
+                }),
+            ),
+        );
+        drop(state);
+
+        // 组件卸载后取消登记，同一个键已经被新的信号占用时保留新的
+        on_cleanup(move || {
+            let mut state = HOT_STATE.lock().unwrap();
+            if state
+                .signals
+                .get(key)
+                .is_some_and(|(current, _)| *current == id)
+            {
+                state.signals.remove(key);
+            }
+        });
+
+        signal
+    }
+
+    /// 保存所有当前存在的信号
+    fn save() {
+        let Ok(state) = HOT_STATE.try_lock() else {
+            return;
+        };
+        let snapshot: HashMap<&str, Value> = state
+            .signals
+            .iter()
+            .filter_map(|(key, (_, snapshot))| Some((*key, snapshot()?)))
+            .collect();
+        if snapshot.is_empty() {
+            return;
+        }
+        if let (Some(storage), Ok(json)) = (storage(), serde_json::to_string(&snapshot)) {
+            let _ = storage.set_item(STORAGE_KEY, &json);
+        }
+    }
+
+    fn storage() -> Option<Storage> {
+        web_sys::window()?.session_storage().ok().flatten()
+    }
+}
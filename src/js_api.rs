@@ -0,0 +1,308 @@
+//! JavaScript 控制接口
+//!
+//! 宿主页面可以通过 wasm 模块导出的函数控制应用：
+//!
+//! ```js
+//! import { navigate, set_theme, notify, get_state, on_route_change } from "./pkg/leptos_vite_template.js";
+//!
+//! const subscription = on_route_change(({ path }) => console.log("route:", path));
+//! navigate("/md-demo");
+//! set_theme("dark");
+//! await notify("Hello from the host page");
+//! console.log(get_state().route.path);
+//! subscription.unsubscribe();
+//! ```
+//!
+//! 这些函数直接使用应用内部的路由、认证与功能开关服务，行为与组件中的调用一致。
+//! 导出函数作用于最早挂载且仍存在的实例；同一页面挂载多个实例时，
+//! 使用 [`AppHandle`](crate::mount::AppHandle) 上的同名方法控制指定实例。
+//! 主题与通知是页面级的，所有实例共享。
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_navigate};
+use leptos_router::location::Location;
+use leptos_router::NavigateOptions;
+use serde::Serialize;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::auth::use_auth;
+use crate::bindings::mdui::{MduiService, Theme};
+use crate::bindings::mdui_options::SnackbarOptions;
+use crate::components::user_card::User;
+use crate::env::{use_app_config, AppConfig, BuildInfo, BUILD_INFO};
+use crate::features::use_feature_flags;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+export type ThemeMode = "light" | "dark" | "auto";
+
+export interface RouteChange {
+  /** 相对于 baseUrl 的路径，可以直接传给 navigate() */
+  path: string;
+  search: string;
+  hash: string;
+}
+
+export interface AppUser {
+  id: number;
+  name: string;
+  email: string;
+  avatar: string | null;
+  role: string;
+}
+
+export interface AppState {
+  route: RouteChange;
+  theme: ThemeMode;
+  user: AppUser | null;
+  featureFlags: Record<string, boolean>;
+  config: {
+    baseUrl: string;
+    appTitle: string;
+    env: "development" | "production" | "github-pages";
+    logFilter: string | null;
+    routerMode: "history" | "hash";
+    transitions: {
+      enter: "none" | "fade" | "slide";
+      leave: "none" | "fade" | "slide";
+      durationMs: number;
+    };
+    errorEndpoint: string | null;
+  };
+  build: {
+    version: string;
+    gitSha: string | null;
+    gitDirty: boolean;
+    timestamp: number;
+  };
+}
+"#;
+
+/// 应用实例的编号，由 `mount_app` 分配并注入上下文
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstanceId(pub u32);
+
+thread_local! {
+    /// 已挂载实例的控制器，按挂载顺序排列
+    static INSTANCES: RefCell<Vec<(InstanceId, Controller)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_SUBSCRIPTION: Cell<u32> = const { Cell::new(0) };
+}
+
+/// 路由的跳转函数
+type Navigate = Rc<dyn Fn(&str, NavigateOptions)>;
+
+/// 路由变化的订阅者
+type Listeners = Rc<RefCell<Vec<(u32, js_sys::Function)>>>;
+
+/// 单个实例的服务，在 `<Router>` 内部由 [`JsApiBridge`] 登记
+#[derive(Clone)]
+struct Controller {
+    navigate: Navigate,
+    location: Location,
+    config: RwSignal<AppConfig>,
+    listeners: Listeners,
+    state: Rc<dyn Fn() -> AppState>,
+}
+
+/// [`get_state`] 返回的快照
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppState {
+    route: RouteChange,
+    theme: &'static str,
+    user: Option<User>,
+    feature_flags: BTreeMap<String, bool>,
+    config: AppConfig,
+    build: BuildInfo,
+}
+
+/// [`on_route_change`] 回调的参数
+#[derive(Serialize)]
+struct RouteChange {
+    path: String,
+    search: String,
+    hash: String,
+}
+
+/// 把当前实例的服务登记到 JavaScript 控制接口
+///
+/// 放在 `<Router>` 内部，由 `mount_app` 挂载的实例才会登记，卸载时自动取消。
+#[component]
+pub fn JsApiBridge() -> impl IntoView {
+    let Some(id) = use_context::<InstanceId>() else {
+        return;
+    };
+
+    let navigate = use_navigate();
+    let location = use_location();
+    let config = use_app_config();
+    let auth = use_auth();
+    let flags = use_feature_flags();
+    let controller = Controller {
+        navigate: Rc::new(navigate),
+        location: location.clone(),
+        config,
+        listeners: Listeners::default(),
+        state: Rc::new(move || AppState {
+            route: route_change(&location, config),
+            theme: MduiService::get_theme().unwrap_or_default().as_str(),
+            user: auth.user(),
+            feature_flags: flags.all().into_iter().collect(),
+            config: config.get(),
+            build: BUILD_INFO,
+        }),
+    };
+
+    // 路径、查询串或片段变化时通知订阅者
+    let emitter = controller.clone();
+    Effect::new(move |_| {
+        emitter.location.pathname.track();
+        emitter.location.search.track();
+        emitter.location.hash.track();
+
+        let event = route_change(&emitter.location, emitter.config);
+        let Ok(event) = to_js(&event) else {
+            return;
+        };
+        // 回调中可能取消订阅，先复制一份
+        let listeners: Vec<_> = emitter.listeners.borrow().clone();
+        for (_, callback) in listeners {
+            if let Err(err) = callback.call1(&JsValue::NULL, &event) {
+                log::warn!("route change listener failed: {err:?}");
+            }
+        }
+    });
+
+    INSTANCES.with_borrow_mut(|instances| instances.push((id, controller)));
+    on_cleanup(move || {
+        INSTANCES.with_borrow_mut(|instances| instances.retain(|(registered, _)| *registered != id))
+    });
+}
+
+/// 路由变化的订阅，调用 `unsubscribe()` 取消
+#[wasm_bindgen]
+pub struct RouteSubscription {
+    instance: InstanceId,
+    id: u32,
+}
+
+#[wasm_bindgen]
+impl RouteSubscription {
+    /// 取消订阅，实例已卸载时不做任何事
+    pub fn unsubscribe(self) {
+        let _ = with_instance(Some(self.instance), |_, controller| {
+            controller
+                .listeners
+                .borrow_mut()
+                .retain(|(id, _)| *id != self.id);
+        });
+    }
+}
+
+/// 跳转到 `path`，与 `<A>` 一样相对于 `baseUrl` 解析
+#[wasm_bindgen]
+pub fn navigate(path: &str) -> Result<(), JsValue> {
+    navigate_in(None, path)
+}
+
+/// 切换页面主题
+#[wasm_bindgen]
+pub fn set_theme(
+    #[wasm_bindgen(unchecked_param_type = "ThemeMode")] mode: &str,
+) -> Result<(), JsValue> {
+    let theme = Theme::parse(mode).ok_or_else(|| {
+        JsValue::from_str(&format!(
+            "Invalid theme {mode:?}, expected \"light\", \"dark\" or \"auto\""
+        ))
+    })?;
+    Ok(MduiService::set_theme(theme)?)
+}
+
+/// 显示一条 Snackbar 通知，通知出现后 Promise 立即完成，不等待关闭
+#[wasm_bindgen]
+pub async fn notify(message: String) -> Result<(), JsValue> {
+    Ok(MduiService::show_snackbar(&SnackbarOptions::new(message)).await?)
+}
+
+/// 当前路由、主题、登录用户、功能开关、运行时配置与构建信息的快照
+#[wasm_bindgen(unchecked_return_type = "AppState")]
+pub fn get_state() -> Result<JsValue, JsValue> {
+    get_state_in(None)
+}
+
+/// 订阅路由变化，`callback` 在每次跳转（包括前进、后退）完成后调用
+#[wasm_bindgen]
+pub fn on_route_change(
+    #[wasm_bindgen(unchecked_param_type = "(route: RouteChange) => void")]
+    callback: js_sys::Function,
+) -> Result<RouteSubscription, JsValue> {
+    on_route_change_in(None, callback)
+}
+
+pub(crate) fn navigate_in(instance: Option<InstanceId>, path: &str) -> Result<(), JsValue> {
+    let navigate = with_instance(instance, |_, controller| controller.navigate.clone())?;
+    // 在 borrow 之外调用，跳转过程中可能再次进入控制接口
+    navigate(path, NavigateOptions::default());
+    Ok(())
+}
+
+pub(crate) fn get_state_in(instance: Option<InstanceId>) -> Result<JsValue, JsValue> {
+    let state = with_instance(instance, |_, controller| controller.state.clone())?;
+    to_js(&state())
+}
+
+pub(crate) fn on_route_change_in(
+    instance: Option<InstanceId>,
+    callback: js_sys::Function,
+) -> Result<RouteSubscription, JsValue> {
+    let id = NEXT_SUBSCRIPTION.replace(NEXT_SUBSCRIPTION.get() + 1);
+    with_instance(instance, |instance, controller| {
+        controller.listeners.borrow_mut().push((id, callback));
+        RouteSubscription { instance, id }
+    })
+}
+
+/// 在指定实例（`None` 时为最早挂载的实例）的控制器上执行 `f`
+fn with_instance<T>(
+    instance: Option<InstanceId>,
+    f: impl FnOnce(InstanceId, &Controller) -> T,
+) -> Result<T, JsValue> {
+    INSTANCES.with_borrow(|instances| {
+        instances
+            .iter()
+            .find(|(id, _)| instance.is_none_or(|instance| *id == instance))
+            .map(|(id, controller)| f(*id, controller))
+            .ok_or_else(not_mounted)
+    })
+}
+
+fn not_mounted() -> JsValue {
+    JsValue::from_str("No app instance is mounted")
+}
+
+/// 当前地址，路径去掉 `baseUrl` 前缀
+fn route_change(location: &Location, config: RwSignal<AppConfig>) -> RouteChange {
+    let pathname = location.pathname.get_untracked();
+    let base = config.with_untracked(|c| c.base_url.trim_end_matches('/').to_string());
+    let path = match pathname.strip_prefix(&base) {
+        Some("") => "/".to_string(),
+        Some(rest) if rest.starts_with('/') => rest.to_string(),
+        _ => pathname,
+    };
+    RouteChange {
+        path,
+        search: location.search.get_untracked(),
+        hash: location.hash.get_untracked(),
+    }
+}
+
+/// 通过 JSON 转换为 JavaScript 对象
+fn to_js(value: &impl Serialize) -> Result<JsValue, JsValue> {
+    let json = serde_json::to_string(value).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    js_sys::JSON::parse(&json)
+}
@@ -0,0 +1,39 @@
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+
+use crate::components::nav_bar::NavBar;
+use crate::env::use_app_config;
+
+/// 顶部标题区是否显示
+///
+/// 由外壳布局提供，页面渲染时按路由表中的 `hero` 字段设置。
+#[derive(Debug, Clone, Copy)]
+pub struct HeroVisibility(pub RwSignal<bool>);
+
+/// 应用外壳布局 - 标题区、导航栏与页面内容
+#[component]
+pub fn AppShell() -> impl IntoView {
+    let config = use_app_config();
+    let hero = RwSignal::new(true);
+    provide_context(HeroVisibility(hero));
+
+    view! {
+        <Show when=move || hero.get()>
+            <div class="hero-section">
+                // 🖼️ 使用 public 目录中的静态资源（相对路径，兼容 GitHub Pages）
+                <img src="icons/logo.svg" alt="Leptos Logo" class="leptos-logo" />
+                <h1>{move || config.with(|c| c.app_title.clone())}</h1>
+                <p>"静态资源演示 - Logo 来自 /public/icons/logo.svg"</p>
+                <p>
+                    <small>"Base URL: " {move || config.with(|c| c.base_url.clone())}</small>
+                </p>
+            </div>
+        </Show>
+
+        <NavBar />
+
+        <main>
+            <Outlet />
+        </main>
+    }
+}
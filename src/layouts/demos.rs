@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, A};
+
+use crate::env::use_app_config;
+use crate::features::use_feature_flags;
+use crate::routes::{registered_routes, RouteGroup};
+
+/// 演示区布局 - 侧边栏列出所有演示页面
+#[component]
+pub fn DemosLayout() -> impl IntoView {
+    let env = use_app_config().with_untracked(|c| c.env);
+    let flags = use_feature_flags();
+
+    let links = move || {
+        let mut routes: Vec<_> = registered_routes(env)
+            .filter(|route| route.group == RouteGroup::Demo)
+            .filter(|route| route.feature.is_none_or(|name| flags.is_enabled(name)))
+            .collect();
+        routes.sort_by_key(|route| route.order);
+        routes
+            .into_iter()
+            .map(|route| {
+                view! {
+                    <li>
+                        <A href=route.path on:mouseenter=move |_| route.prefetch()>
+                            {route.icon} " " {route.title}
+                        </A>
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="demos-layout">
+            <aside class="demos-sidebar">
+                <p>"演示页面"</p>
+                <ul>{links}</ul>
+            </aside>
+            <section class="demos-content">
+                <Outlet />
+            </section>
+        </div>
+    }
+}
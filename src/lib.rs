@@ -1,76 +1,139 @@
 use leptos::prelude::*;
-use leptos_router::{components::*, path};
+use leptos_meta::{provide_meta_context, Title};
+use leptos_router::components::*;
+use leptos_router::path;
+#[cfg(feature = "auto-start")]
 use wasm_bindgen::prelude::wasm_bindgen;
-use wasm_bindgen::JsCast;
+#[cfg(feature = "auto-start")]
+use wasm_bindgen::JsValue;
+#[cfg(feature = "auto-start")]
+use wasm_bindgen_futures::spawn_local;
 
 // Modules
-mod bindings;
+mod auth;
+pub mod bindings;
 mod components;
+mod config;
+#[cfg(test)]
+mod config_check;
+mod crash;
+mod debug;
 mod env;
+mod features;
+mod hash_history;
+mod hot_state;
+mod js_api;
+mod layouts;
+mod logging;
+mod mount;
 mod pages;
+mod parsing;
+mod query_state;
+mod reporting;
+mod routes;
+mod spa_redirect;
+mod transitions;
 
 // 导入环境变量处理函数
-use env::{get_app_title, get_base_url, get_debug_info};
+use env::use_app_config;
 
-// Top-Level pages
-use crate::pages::complex_props_demo::ComplexPropsDemo;
-use crate::pages::home::Home;
-use crate::pages::mdui_demo::MduiDemo;
-use crate::pages::mdui_js_api_demo::MduiJsApiDemo;
+use crate::components::dev_tools::DevTools;
+use crate::components::error_fallback::ErrorFallback;
+use crate::components::scroll_restoration::ScrollRestoration;
+use crate::auth::provide_auth;
+use crate::js_api::JsApiBridge;
+use crate::features::provide_feature_flags;
+use crate::layouts::app_shell::AppShell;
+use crate::layouts::demos::DemosLayout;
+use crate::routes::{GroupRoutes, NotFoundRoute, RouteGroup};
+use crate::transitions::provide_transitions;
 
 #[component]
 pub fn App() -> impl IntoView {
-    let base_url = get_base_url();
-    let app_title = get_app_title();
+    let config = use_app_config();
+    let base_url = config.with_untracked(|c| c.base_url.clone());
+    let app_env = config.with_untracked(|c| c.env);
+    // 懒加载页面下载期间显示进度条
+    let (is_routing, set_is_routing) = signal(false);
+
+    provide_meta_context();
+    provide_feature_flags();
+    provide_auth();
+    let root = NodeRef::new();
+    provide_transitions(config.with_untracked(|c| c.transitions), root);
+
+    // 页面标题统一格式化为 "页面 · 应用标题"
+    let title_formatter = move |page: String| {
+        let app_title = config.with(|c| c.app_title.clone());
+        if page.is_empty() {
+            app_title
+        } else {
+            format!("{} · {}", page, app_title)
+        }
+    };
 
     view! {
-        <div class="app-container">
-            <div class="hero-section">
-                // 🖼️ 使用 public 目录中的静态资源（相对路径，兼容 GitHub Pages）
-                <img src="icons/logo.svg" alt="Leptos Logo" class="leptos-logo" />
-                <h1>{app_title}</h1>
-                <p>"静态资源演示 - Logo 来自 /public/icons/logo.svg"</p>
-                <p>
-                    <small>"Base URL: " {base_url.clone()}</small>
-                </p>
-            </div>
+        <Title formatter=title_formatter />
+
+        <div class="app-container" node_ref=root>
+            <Router base=base_url set_is_routing>
+                <div class="routing-progress">
+                    <RoutingProgress is_routing max_time=std::time::Duration::from_millis(500) />
+                </div>
+                <ScrollRestoration is_routing />
+                <JsApiBridge />
 
-            <Router base=base_url>
-                <nav>
-                    <A href="">Home</A>
-                    " | "
-                    <A href="complex-props-demo">Complex Props Demo</A>
-                    " | "
-                    <A href="md-demo">Mdui Demo</A>
-                    " | "
-                    <A href="md-js-api-demo">Mdui Js Api Demo</A>
-                </nav>
+                <ErrorBoundary fallback=|errors| view! { <ErrorFallback errors /> }>
+                    // 未匹配的路径由外壳布局中的 NotFoundRoute 处理，这里的回退不会显示
+                    <Routes fallback=|| ()>
+                        <ParentRoute path=path!("") view=AppShell>
+                            <GroupRoutes env=app_env group=RouteGroup::Core />
+                            <GroupRoutes env=app_env group=RouteGroup::Dev />
+                            <ParentRoute path=path!("") view=DemosLayout>
+                                <GroupRoutes env=app_env group=RouteGroup::Demo />
+                            </ParentRoute>
+                            <NotFoundRoute />
+                        </ParentRoute>
+                    </Routes>
+                </ErrorBoundary>
 
-                <Routes fallback=|| view! { <p>"Not Found!"</p> }>
-                    <Route path=path!("/") view=Home />
-                    <Route path=path!("/complex-props-demo") view=ComplexPropsDemo />
-                    <Route path=path!("/md-demo") view=MduiDemo />
-                    <Route path=path!("/md-js-api-demo") view=MduiJsApiDemo />
-                </Routes>
+                <Show when=move || app_env.mounts_dev_tools()>
+                    <DevTools />
+                </Show>
+                {debug_overlay(app_env.mounts_dev_tools())}
             </Router>
         </div>
     }
 }
 
-#[wasm_bindgen(start)]
-pub fn start() {
-    _ = console_log::init_with_level(log::Level::Debug);
-    console_error_panic_hook::set_once();
+/// 调试浮层，release 构建中不存在
+#[cfg(debug_assertions)]
+fn debug_overlay(enabled: bool) -> impl IntoView {
+    use crate::components::debug_overlay::DebugOverlay;
 
-    // 输出调试信息
-    log::info!("{}", get_debug_info());
+    enabled.then(|| view! { <DebugOverlay /> })
+}
+
+#[cfg(not(debug_assertions))]
+fn debug_overlay(_enabled: bool) -> impl IntoView {}
 
-    let element = web_sys::window()
-        .unwrap()
-        .document()
-        .unwrap()
-        .get_element_by_id("leptos-app")
-        .expect("Element with id 'leptos-app' not found");
+/// 页面加载后自动挂载到 `#leptos-app`
+///
+/// 作为组件嵌入到其他页面时关闭默认的 `auto-start` feature，由宿主页面调用 [`mount::mount_app`]。
+#[cfg(feature = "auto-start")]
+#[wasm_bindgen(start)]
+pub fn start() {
+    spawn_local(async {
+        match mount::mount_app(format!("#{}", crash::MOUNT_ID), JsValue::UNDEFINED).await {
+            Ok(handle) => AUTO_STARTED.with_borrow_mut(|app| *app = Some(handle)),
+            Err(err) => web_sys::console::error_2(&"Failed to mount app:".into(), &err),
+        }
+    });
+}
 
-    mount_to(element.unchecked_into(), || view! { <App /> }).forget();
+#[cfg(feature = "auto-start")]
+thread_local! {
+    /// 自动挂载的实例，随页面一直存在
+    static AUTO_STARTED: std::cell::RefCell<Option<mount::AppHandle>> =
+        const { std::cell::RefCell::new(None) };
 }
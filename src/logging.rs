@@ -0,0 +1,243 @@
+//! 日志系统
+//!
+//! 自定义的 [`log::Log`] 实现：日志级别与按模块过滤规则来自配置，
+//! 输出到浏览器控制台的同时在环形缓冲区中保留最近的记录，供 `/logs` 页面查看与导出。
+//! error 级别的记录还会交给 [`reporting`] 上报。
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex, RwLock};
+
+use leptos::prelude::*;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::{Serialize, Serializer};
+use wasm_bindgen::JsValue;
+use web_sys::console;
+
+use crate::crash::error_id;
+use crate::reporting::{self, ReportKind};
+
+/// 环形缓冲区容量
+const BUFFER_CAPACITY: usize = 500;
+
+static LOGGER: LazyLock<AppLogger> = LazyLock::new(AppLogger::default);
+
+/// 一条日志记录
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    /// 毫秒级 Unix 时间戳
+    pub timestamp: f64,
+    #[serde(serialize_with = "serialize_level")]
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+fn serialize_level<S: Serializer>(level: &Level, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(level.as_str())
+}
+
+/// 日志过滤规则
+///
+/// 语法与 `env_logger` 相同：`warn,leptos_vite_template::pages=debug`，
+/// 不带模块名的条目设置默认级别，带模块名的条目按最长前缀匹配。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl LogFilter {
+    /// 只有默认级别的过滤规则
+    pub fn new(default: LevelFilter) -> Self {
+        Self {
+            default,
+            modules: Vec::new(),
+        }
+    }
+
+    /// 解析过滤规则，`default` 为未指定默认级别时使用的级别
+    pub fn parse(spec: &str, default: LevelFilter) -> Result<Self, String> {
+        let mut filter = Self::new(default);
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => {
+                    let level = parse_level(level)?;
+                    filter.modules.push((module.trim().to_string(), level));
+                }
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+
+        // 更长（更具体）的模块路径优先匹配
+        filter.modules.sort_by_key(|(module, _)| Reverse(module.len()));
+        Ok(filter)
+    }
+
+    /// 指定模块的生效级别
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .find(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    /// 所有规则中最宽松的级别，用于 [`log::set_max_level`]
+    pub fn max_level(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    level
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid log level {:?}", level.trim()))
+}
+
+#[derive(Default)]
+struct AppLogger {
+    filter: RwLock<Option<LogFilter>>,
+    buffer: Mutex<VecDeque<LogEntry>>,
+    trigger: ArcTrigger,
+}
+
+impl Log for AppLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.filter
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|filter| metadata.level() <= filter.level_for(metadata.target()))
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let entry = LogEntry {
+            timestamp: js_sys::Date::now(),
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        };
+
+        let line =
+            JsValue::from_str(&format!("[{} {}] {}", entry.level, entry.target, entry.message));
+        match entry.level {
+            Level::Error => console::error_1(&line),
+            Level::Warn => console::warn_1(&line),
+            Level::Info => console::info_1(&line),
+            Level::Debug => console::log_1(&line),
+            Level::Trace => console::debug_1(&line),
+        }
+
+        // 错误记录同时上报，上报器自身的日志除外
+        if entry.level == Level::Error && entry.target != reporting::TARGET {
+            reporting::report(
+                error_id(),
+                ReportKind::Log,
+                entry.message.clone(),
+                Some(entry.target.clone()),
+            );
+        }
+
+        {
+            let mut buffer = self.buffer.lock().unwrap();
+            if buffer.len() == BUFFER_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(entry);
+        }
+        self.trigger.notify();
+    }
+
+    fn flush(&self) {}
+}
+
+/// 安装全局日志器
+pub fn init(filter: LogFilter) {
+    if log::set_logger(&*LOGGER).is_ok() {
+        set_filter(filter);
+    }
+}
+
+/// 替换当前的过滤规则
+pub fn set_filter(filter: LogFilter) {
+    log::set_max_level(filter.max_level());
+    *LOGGER.filter.write().unwrap() = Some(filter);
+}
+
+/// 最近的日志记录（按时间顺序），在响应式上下文中调用时会追踪新记录
+pub fn records() -> Vec<LogEntry> {
+    LOGGER.trigger.track();
+    LOGGER.buffer.lock().unwrap().iter().cloned().collect()
+}
+
+/// 清空环形缓冲区
+pub fn clear() {
+    LOGGER.buffer.lock().unwrap().clear();
+    LOGGER.trigger.notify();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_spec_keeps_default_level() {
+        let filter = LogFilter::parse("", LevelFilter::Warn).unwrap();
+        assert_eq!(filter.level_for("app"), LevelFilter::Warn);
+        assert_eq!(filter.max_level(), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn bare_level_overrides_default() {
+        let filter = LogFilter::parse(" DEBUG ", LevelFilter::Warn).unwrap();
+        assert_eq!(filter.level_for("app"), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn module_directives_match_path_prefixes() {
+        let filter = LogFilter::parse("info,app::routes=trace", LevelFilter::Warn).unwrap();
+        assert_eq!(filter.level_for("app::routes"), LevelFilter::Trace);
+        assert_eq!(filter.level_for("app::routes::lazy"), LevelFilter::Trace);
+        // 只按完整的路径段匹配
+        assert_eq!(filter.level_for("app::routes_extra"), LevelFilter::Info);
+        assert_eq!(filter.level_for("app"), LevelFilter::Info);
+    }
+
+    #[test]
+    fn longest_module_path_wins() {
+        let filter =
+            LogFilter::parse("app::routes::lazy=off, app=debug", LevelFilter::Warn).unwrap();
+        assert_eq!(filter.level_for("app::routes::lazy"), LevelFilter::Off);
+        assert_eq!(filter.level_for("app::routes"), LevelFilter::Debug);
+        assert_eq!(filter.level_for("other"), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn max_level_is_most_verbose_rule() {
+        let filter = LogFilter::parse("error,app=info", LevelFilter::Warn).unwrap();
+        assert_eq!(filter.max_level(), LevelFilter::Info);
+    }
+
+    #[test]
+    fn rejects_invalid_levels() {
+        assert_eq!(
+            LogFilter::parse("app=loud", LevelFilter::Warn).unwrap_err(),
+            "invalid log level \"loud\""
+        );
+        assert!(LogFilter::parse("verbose", LevelFilter::Warn).is_err());
+    }
+}
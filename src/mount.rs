@@ -0,0 +1,162 @@
+//! 挂载 API
+//!
+//! [`mount_app`] 把应用挂载到页面中的任意元素上，可以在同一页面中挂载多个实例，
+//! 每个实例有独立的运行时配置与路由基础路径。返回的 [`AppHandle`] 用于控制与卸载实例，
+//! 控制方法与 [`crate::js_api`] 导出的同名函数一致。
+//!
+//! 页面级的资源在所有实例之间共享：日志器与 panic hook 只安装一次；错误上报器与 hash 路由模式
+//! 以第一个实例的配置为准；日志过滤规则以最后挂载的实例为准。
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Once;
+
+use leptos::mount::mount_to;
+use leptos::prelude::*;
+use leptos::tachys::view::any_view::AnyViewState;
+use serde::Deserialize;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::HtmlElement;
+
+use crate::env::{get_debug_info, provide_app_config, AppConfig, RouterMode, CONFIG_PATH};
+use crate::js_api::{self, InstanceId, RouteSubscription};
+use crate::{crash, hash_history, logging, reporting, spa_redirect, App};
+
+static RUNTIME_INIT: Once = Once::new();
+
+static NEXT_INSTANCE: AtomicU32 = AtomicU32::new(0);
+
+/// `mount_app` 的选项
+///
+/// ```js
+/// await mount_app("#widget", {
+///   configUrl: "/widget/config.json", // 设为 null 跳过加载配置文件
+///   config: { baseUrl: "/widget/", appTitle: "Widget" },
+/// });
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MountOptions {
+    /// 运行时配置文件地址，`None` 时只使用编译时配置与 `config`
+    config_url: Option<String>,
+    /// 覆盖配置文件中的字段，格式与 `config.json` 相同
+    config: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Default for MountOptions {
+    fn default() -> Self {
+        Self {
+            config_url: Some(CONFIG_PATH.to_string()),
+            config: serde_json::Map::new(),
+        }
+    }
+}
+
+impl MountOptions {
+    fn from_js(options: &JsValue) -> Result<Self, JsValue> {
+        if options.is_undefined() || options.is_null() {
+            return Ok(Self::default());
+        }
+        let json = js_sys::JSON::stringify(options)?
+            .as_string()
+            .unwrap_or_default();
+        serde_json::from_str(&json).map_err(|e| format!("Invalid mount options: {e}").into())
+    }
+}
+
+/// 已挂载的应用实例
+#[wasm_bindgen]
+pub struct AppHandle {
+    id: InstanceId,
+    root: HtmlElement,
+    mounted: Option<UnmountHandle<AnyViewState>>,
+}
+
+#[wasm_bindgen]
+impl AppHandle {
+    /// 在这个实例中跳转，见 [`js_api::navigate`]
+    pub fn navigate(&self, path: &str) -> Result<(), JsValue> {
+        js_api::navigate_in(Some(self.id), path)
+    }
+
+    /// 这个实例的状态快照，见 [`js_api::get_state`]
+    #[wasm_bindgen(unchecked_return_type = "AppState")]
+    pub fn get_state(&self) -> Result<JsValue, JsValue> {
+        js_api::get_state_in(Some(self.id))
+    }
+
+    /// 订阅这个实例的路由变化，见 [`js_api::on_route_change`]
+    pub fn on_route_change(
+        &self,
+        #[wasm_bindgen(unchecked_param_type = "(route: RouteChange) => void")]
+        callback: js_sys::Function,
+    ) -> Result<RouteSubscription, JsValue> {
+        js_api::on_route_change_in(Some(self.id), callback)
+    }
+
+    /// 卸载实例：清理响应式 Owner 与事件监听，并移除渲染的 DOM
+    pub fn unmount(mut self) {
+        crash::unregister_root(&self.root);
+        self.mounted.take();
+    }
+}
+
+/// 把应用挂载到 `selector` 匹配的元素上
+///
+/// 先加载运行时配置再挂载，找不到元素或配置无效时返回错误。
+///
+/// 同一页面挂载多个实例时，以下行为绑定到较早挂载的实例，而不是每个实例各自生效：
+///
+/// - hash 路由模式的地址栏镜像只安装一次，使用第一个启用 hash 模式的实例的 `baseUrl`，
+///   之后的实例即使配置了不同的基础路径也沿用它；
+/// - 错误上报器只初始化一次，使用第一个实例的 `errorEndpoint` 与 `env`；
+/// - [`crate::js_api`] 导出的 `navigate`、`get_state`、`on_route_change` 作用于最早挂载且仍存在的实例，
+///   控制指定实例时使用返回的 [`AppHandle`] 上的同名方法；`set_theme` 与 `notify` 是页面级的。
+///
+/// 日志过滤规则以最后挂载的实例为准；panic 时所有实例都会被替换为崩溃页面。
+#[wasm_bindgen]
+pub async fn mount_app(selector: String, options: JsValue) -> Result<AppHandle, JsValue> {
+    init_runtime();
+
+    let options = MountOptions::from_js(&options)?;
+    let root = document()
+        .query_selector(&selector)?
+        .ok_or_else(|| format!("No element matches {selector:?}"))?
+        .dyn_into::<HtmlElement>()
+        .map_err(|_| format!("{selector:?} is not an HTML element"))?;
+
+    let config = AppConfig::load(options.config_url.as_deref(), options.config).await?;
+    logging::set_filter(config.log_filter());
+    // 是否输出调试信息由运行时加载的环境决定
+    if config.env.shows_debug_info() {
+        log::info!("{}", get_debug_info(&config));
+    }
+    reporting::init(config.error_endpoint.clone(), config.env);
+    spa_redirect::restore(&config.base_url);
+    if config.router_mode == RouterMode::Hash {
+        hash_history::install(&config.base_url);
+    }
+
+    let id = InstanceId(NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed));
+    crash::register_root(root.clone().into(), config.env);
+    let mounted = mount_to(root.clone(), move || {
+        provide_context(id);
+        provide_app_config(config);
+        view! { <App /> }.into_any()
+    });
+    Ok(AppHandle {
+        id,
+        root,
+        mounted: Some(mounted),
+    })
+}
+
+/// 页面级的初始化，只在第一次挂载时执行
+fn init_runtime() {
+    RUNTIME_INIT.call_once(|| {
+        // 先使用编译时配置初始化日志，运行时配置加载后再更新过滤规则
+        let build_config = AppConfig::default();
+        logging::init(build_config.log_filter());
+        crash::install_panic_hook();
+    });
+}
@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+
+use crate::env::{use_app_config, BUILD_INFO};
+
+/// 关于页面 - 显示构建信息，方便在问题反馈中引用具体构建
+#[component]
+pub fn About() -> impl IntoView {
+    let config = use_app_config();
+    let full_sha = BUILD_INFO.git_sha.unwrap_or("unknown");
+
+    view! {
+        <div class="container">
+            <h2>"关于"</h2>
+
+            <table style="margin: 0 auto; text-align: left; border-collapse: collapse;">
+                <tbody>
+                    <tr>
+                        <th style="padding: 4px 12px;">"应用"</th>
+                        <td>{move || config.with(|c| c.app_title.clone())}</td>
+                    </tr>
+                    <tr>
+                        <th style="padding: 4px 12px;">"版本"</th>
+                        <td>{BUILD_INFO.version}</td>
+                    </tr>
+                    <tr>
+                        <th style="padding: 4px 12px;">"提交"</th>
+                        <td>
+                            <code title=full_sha>{BUILD_INFO.short_sha()}</code>
+                        </td>
+                    </tr>
+                    <tr>
+                        <th style="padding: 4px 12px;">"构建时间"</th>
+                        <td>{BUILD_INFO.build_time()}</td>
+                    </tr>
+                    <tr>
+                        <th style="padding: 4px 12px;">"环境"</th>
+                        <td>{move || config.with(|c| c.env.to_string())}</td>
+                    </tr>
+                </tbody>
+            </table>
+
+            <p style="color: #666; font-size: 14px;">
+                "反馈问题时请附上以下信息："
+                <br />
+                <code>{BUILD_INFO.to_string()}</code>
+            </p>
+        </div>
+    }
+}
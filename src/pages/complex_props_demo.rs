@@ -2,6 +2,7 @@ use crate::components::{
     callback_test::CallbackTest,
     user_card::{CardConfig, User, UserCard},
 };
+use crate::query_state::use_query_state;
 use leptos::prelude::*;
 
 #[component]
@@ -31,8 +32,11 @@ pub fn ComplexPropsDemo() -> impl IntoView {
         role: "产品经理".to_string(),
     };
 
+    // 卡片配置与查询参数同步，例如 ?show_email=false&theme=dark 可以直接分享当前的显示效果
+    let card_config = use_query_state::<CardConfig>();
+
     view! {
-        <div style="min-height: 100vh; background: #f5f5f5; padding: 20px;">
+        <div class="complex-props-demo">
             <div style="max-width: 1200px; margin: 0 auto;">
 
                 <div style="text-align: center; margin-bottom: 40px;">
@@ -47,36 +51,51 @@ pub fn ComplexPropsDemo() -> impl IntoView {
                     <h2 style="color: #333; margin-bottom: 20px; border-bottom: 2px solid #007bff; padding-bottom: 8px;">
                         "结构体 Props - 用户卡片组件"
                     </h2>
+                    <div class="card-config-controls" style="display: flex; flex-wrap: wrap; gap: 20px; justify-content: center; margin-bottom: 16px;">
+                        <label>
+                            <input
+                                type="checkbox"
+                                prop:checked=move || card_config.with(|c| c.show_email)
+                                on:change=move |ev| {
+                                    card_config.update(|c| c.show_email = event_target_checked(&ev))
+                                }
+                            />
+                            " 显示邮箱"
+                        </label>
+                        <label>
+                            <input
+                                type="checkbox"
+                                prop:checked=move || card_config.with(|c| c.show_role)
+                                on:change=move |ev| {
+                                    card_config.update(|c| c.show_role = event_target_checked(&ev))
+                                }
+                            />
+                            " 显示角色"
+                        </label>
+                        <label>
+                            "主题 "
+                            <select
+                                prop:value=move || card_config.with(|c| c.theme.clone())
+                                on:change=move |ev| {
+                                    card_config.update(|c| c.theme = event_target_value(&ev))
+                                }
+                            >
+                                <option value="light">"浅色"</option>
+                                <option value="dark">"深色"</option>
+                            </select>
+                        </label>
+                    </div>
+                    <p style="text-align: center; color: #666; font-size: 14px; margin: 0 0 16px 0;">
+                        "配置会同步到地址栏，复制链接即可分享当前的显示效果"
+                    </p>
                     <div style="display: flex; flex-wrap: wrap; gap: 16px; justify-content: center;">
-                        // 用户1 - 显示所有信息
-                        <UserCard
-                            user=user1
-                            config=CardConfig {
-                                theme: "light".to_string(),
-                                show_email: true,
-                                show_role: true,
-                            }
-                        />
-
-                        // 用户2 - 隐藏邮箱
-                        <UserCard
-                            user=user2
-                            config=CardConfig {
-                                theme: "light".to_string(),
-                                show_email: false,
-                                show_role: true,
-                            }
-                        />
-
-                        // 用户3 - 隐藏角色
-                        <UserCard
-                            user=user3
-                            config=CardConfig {
-                                theme: "light".to_string(),
-                                show_email: true,
-                                show_role: false,
-                            }
-                        />
+                        {move || {
+                            let config = card_config.get();
+                            [user1.clone(), user2.clone(), user3.clone()]
+                                .into_iter()
+                                .map(|user| view! { <UserCard user config=config.clone() /> })
+                                .collect_view()
+                        }}
                     </div>
                 </section>
 
@@ -109,7 +128,8 @@ pub fn ComplexPropsDemo() -> impl IntoView {
                             pub role: String,
                             }
                             
-                            #[derive(Debug, Clone, PartialEq)]
+                            #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+                            #[serde(default)]
                             pub struct CardConfig {
                             pub theme: String,
                             pub show_email: bool,
@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+
+use crate::auth::use_auth;
+
+/// 会员页面 - 演示 `Guard::Authenticated`，未登录时跳转到登录页
+#[component]
+pub fn MembersDemo() -> impl IntoView {
+    let auth = use_auth();
+
+    view! {
+        <div class="container">
+            <h2>"👥 会员专区"</h2>
+            <p>
+                {move || {
+                    auth.user().map(|user| format!("欢迎，{}！只有登录用户才能看到这个页面。", user.name))
+                }}
+            </p>
+            <p style="color: #666; font-size: 14px;">
+                "路由表中将该页面的 " <code>"guard"</code> " 设置为 "
+                <code>"Guard::Authenticated"</code> "，退出登录后再次访问会跳转到登录页，登录后自动返回。"
+            </p>
+        </div>
+    }
+}
+
+/// 开发者页面 - 演示 `Guard::Role`，已登录但角色不符时显示无权访问
+#[component]
+pub fn DevOnlyDemo() -> impl IntoView {
+    let auth = use_auth();
+
+    view! {
+        <div class="container">
+            <h2>"🛠️ 开发者专区"</h2>
+            <p>
+                {move || {
+                    auth.user().map(|user| format!("{}，你拥有 {} 角色。", user.name, user.role))
+                }}
+            </p>
+            <p style="color: #666; font-size: 14px;">
+                "路由表中将该页面的 " <code>"guard"</code> " 设置为 "
+                <code>"Guard::Role(\"开发者\")"</code> "，以其他角色登录时会显示无权访问的提示。"
+            </p>
+        </div>
+    }
+}
@@ -1,63 +1,52 @@
 use crate::components::counter_btn::Button;
+use crate::env::use_app_config;
+use crate::routes::Page;
 use leptos::prelude::*;
+use leptos_router::components::A;
 
 /// Default Home Page
 #[component]
 pub fn Home() -> impl IntoView {
-    view! {
-        <ErrorBoundary fallback=|errors| {
-            view! {
-                <h1>"Uh oh! Something went wrong!"</h1>
-
-                <p>"Errors: "</p>
-                // Render a list of errors as strings - good for development purposes
-                <ul>
-                    {move || {
-                        errors
-                            .get()
-                            .into_iter()
-                            .map(|(_, e)| view! { <li>{e.to_string()}</li> })
-                            .collect_view()
-                    }}
-                </ul>
-            }
-        }>
-
-            <div class="container">
-
-                <picture>
-                    <source
-                        srcset="https://raw.githubusercontent.com/leptos-rs/leptos/main/docs/logos/Leptos_logo_pref_dark_RGB.svg"
-                        media="(prefers-color-scheme: dark)"
-                    />
-                    <img
-                        src="https://raw.githubusercontent.com/leptos-rs/leptos/main/docs/logos/Leptos_logo_RGB.svg"
-                        alt="Leptos Logo"
-                        height="200"
-                        width="400"
-                    />
-                </picture>
-
-                <h1>"Welcome to Leptos"</h1>
+    let show_demos = use_app_config().with_untracked(|c| Page::ComplexPropsDemo.is_registered(c.env));
 
-                <div class="buttons">
-                    <Button />
-                    <Button increment=5 />
-                </div>
+    view! {
+        <div class="container">
+
+            <picture>
+                <source
+                    srcset="https://raw.githubusercontent.com/leptos-rs/leptos/main/docs/logos/Leptos_logo_pref_dark_RGB.svg"
+                    media="(prefers-color-scheme: dark)"
+                />
+                <img
+                    src="https://raw.githubusercontent.com/leptos-rs/leptos/main/docs/logos/Leptos_logo_RGB.svg"
+                    alt="Leptos Logo"
+                    height="200"
+                    width="400"
+                />
+            </picture>
+
+            <h1>"Welcome to Leptos"</h1>
+
+            <div class="buttons">
+                <Button />
+                <Button increment=5 />
+            </div>
 
+            <Show when=move || show_demos>
                 <div style="margin-top: 30px; text-align: center;">
-                    <a
-                        href="/complex-props-demo"
-                        style="display: inline-block; padding: 12px 24px; background: #007bff; color: white; text-decoration: none; border-radius: 8px; font-weight: 500; transition: background-color 0.2s;"
+                    <A
+                        href=Page::ComplexPropsDemo.href()
+                        on:mouseenter=|_| Page::ComplexPropsDemo.route().prefetch()
+                        attr:style="display: inline-block; padding: 12px 24px; background: #007bff; color: white; text-decoration: none; border-radius: 8px; font-weight: 500; transition: background-color 0.2s;"
                     >
                         "🚀 查看复杂 Props 演示"
-                    </a>
+                    </A>
                     <p style="margin-top: 12px; color: #666; font-size: 14px;">
                         "探索结构体、枚举、回调函数等复杂类型的 Props 用法"
                     </p>
                 </div>
+            </Show>
 
-            </div>
-        </ErrorBoundary>
+        </div>
     }
 }
@@ -0,0 +1,98 @@
+use leptos::prelude::*;
+use log::Level;
+
+use crate::logging::{self, LogEntry};
+
+/// 日志查看器 - 仅在开发环境中注册
+#[component]
+pub fn LogViewer() -> impl IntoView {
+    let min_level = RwSignal::new(Level::Trace);
+    let query = RwSignal::new(String::new());
+
+    // 按级别与关键字过滤，最新的记录排在最前
+    let filtered = move || {
+        let min_level = min_level.get();
+        let query = query.get().to_lowercase();
+        logging::records()
+            .into_iter()
+            .rev()
+            .filter(|entry| entry.level <= min_level)
+            .filter(|entry| {
+                query.is_empty()
+                    || entry.message.to_lowercase().contains(&query)
+                    || entry.target.to_lowercase().contains(&query)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let export_href = move || {
+        let json = serde_json::to_string_pretty(&filtered()).unwrap_or_default();
+        format!(
+            "data:application/json;charset=utf-8,{}",
+            String::from(js_sys::encode_uri_component(&json))
+        )
+    };
+
+    view! {
+        <div class="log-viewer">
+            <h2>"日志查看器"</h2>
+
+            <div style="display: flex; gap: 8px; flex-wrap: wrap; margin-bottom: 12px;">
+                <select on:change=move |ev| {
+                    if let Ok(level) = event_target_value(&ev).parse() {
+                        min_level.set(level);
+                    }
+                }>
+                    {Level::iter()
+                        .map(|level| {
+                            view! {
+                                <option value=level.as_str() selected=level == Level::Trace>
+                                    {level.as_str()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <input
+                    type="search"
+                    placeholder="按模块或内容过滤..."
+                    prop:value=move || query.get()
+                    on:input=move |ev| query.set(event_target_value(&ev))
+                />
+                <a href=export_href download="logs.json">
+                    "导出 JSON"
+                </a>
+                <button on:click=move |_| logging::clear()>"清空"</button>
+            </div>
+
+            <p style="color: #666; font-size: 14px;">
+                {move || format!("共 {} 条记录", filtered().len())}
+            </p>
+
+            <table style="width: 100%; border-collapse: collapse; font-family: monospace; font-size: 12px;">
+                <tbody>
+                    {move || filtered().into_iter().map(log_row).collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+fn log_row(entry: LogEntry) -> impl IntoView {
+    let color = match entry.level {
+        Level::Error => "#d32f2f",
+        Level::Warn => "#f57c00",
+        Level::Info => "#1976d2",
+        Level::Debug | Level::Trace => "#666",
+    };
+    let time = js_sys::Date::new(&entry.timestamp.into()).to_locale_time_string("zh-CN");
+
+    view! {
+        <tr style="border-bottom: 1px solid #eee; vertical-align: top;">
+            <td style="padding: 4px; white-space: nowrap;">{String::from(time)}</td>
+            <td style=format!("padding: 4px; color: {};", color)>{entry.level.as_str()}</td>
+            <td style="padding: 4px; color: #888;">{entry.target}</td>
+            <td style="padding: 4px; white-space: pre-wrap;">{entry.message}</td>
+        </tr>
+    }
+}
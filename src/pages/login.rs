@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use leptos_router::NavigateOptions;
+
+use crate::auth::{use_auth, REDIRECT_PARAM, ROLES};
+use crate::components::user_card::User;
+
+/// 登录页 - 演示用，不校验密码
+///
+/// 登录后返回 `?redirect=` 指定的地址，只接受站内路径。
+#[component]
+pub fn Login() -> impl IntoView {
+    let auth = use_auth();
+    let query = use_query_map();
+    let navigate = use_navigate();
+
+    let name = RwSignal::new(String::new());
+    let email = RwSignal::new(String::new());
+    let role = RwSignal::new(ROLES[0].to_string());
+
+    let redirect = move || {
+        query
+            .with_untracked(|q| q.get(REDIRECT_PARAM))
+            .filter(|path| path.starts_with('/') && !path.starts_with("//"))
+            .unwrap_or_else(|| "/".to_string())
+    };
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let name = name.get_untracked();
+        if name.trim().is_empty() {
+            return;
+        }
+        auth.login(User {
+            id: 0,
+            name: name.trim().to_string(),
+            email: email.get_untracked(),
+            avatar: None,
+            role: role.get_untracked(),
+        });
+        navigate(
+            &redirect(),
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        );
+    };
+
+    view! {
+        <div class="container">
+            <h2>"登录"</h2>
+
+            <Show
+                when=move || auth.is_authenticated()
+                fallback=move || {
+                    view! {
+                        <form class="login-form" on:submit=on_submit.clone()>
+                            <label>
+                                "用户名 "
+                                <input
+                                    type="text"
+                                    required
+                                    prop:value=move || name.get()
+                                    on:input=move |ev| name.set(event_target_value(&ev))
+                                />
+                            </label>
+                            <label>
+                                "邮箱 "
+                                <input
+                                    type="email"
+                                    prop:value=move || email.get()
+                                    on:input=move |ev| email.set(event_target_value(&ev))
+                                />
+                            </label>
+                            <label>
+                                "角色 "
+                                <select on:change=move |ev| role.set(event_target_value(&ev))>
+                                    {ROLES
+                                        .iter()
+                                        .map(|r| view! { <option value=*r>{*r}</option> })
+                                        .collect_view()}
+                                </select>
+                            </label>
+                            <button type="submit">"登录"</button>
+                        </form>
+                    }
+                }
+            >
+                <p>
+                    {move || {
+                        auth.user()
+                            .map(|user| format!("已登录为 {}（{}）", user.name, user.role))
+                    }}
+                </p>
+                <button on:click=move |_| auth.logout()>"退出登录"</button>
+            </Show>
+        </div>
+    }
+}
@@ -1,14 +1,15 @@
 use leptos::prelude::*;
 
 use crate::components::mdui_components::*;
+use crate::hot_state::hot_signal;
 // 3. 完整的演示页面
 #[component]
 pub fn MduiDemo() -> impl IntoView {
-    // 使用 RwSignal 进行状态管理
-    let count = RwSignal::new(0);
-    let text_value = RwSignal::new(String::new());
-    let dialog_open = RwSignal::new(false);
-    let chip_selected = RwSignal::new(false);
+    // 使用 RwSignal 进行状态管理，hot_signal 在开发时热重载后保留状态
+    let count = hot_signal("mdui-demo.count", 0);
+    let text_value = hot_signal("mdui-demo.text", String::new());
+    let dialog_open = hot_signal("mdui-demo.dialog-open", false);
+    let chip_selected = hot_signal("mdui-demo.chip-selected", false);
     let snackbar_open = RwSignal::new(false);
 
     view! {
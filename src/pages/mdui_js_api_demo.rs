@@ -1,28 +1,40 @@
+use leptos::html::Div;
 use leptos::prelude::*;
+use send_wrapper::SendWrapper;
 use wasm_bindgen_futures::spawn_local;
 
 use crate::components::mdui_components::MduiButton;
-use crate::bindings::mdui::MduiService;
+use crate::bindings::mdui::{Breakpoint, BreakpointQuery, MduiService, Theme};
+use crate::bindings::mdui_error::MduiError;
+use crate::bindings::mdui_options::{
+    AlertOptions, ColorSchemeOptions, ConfirmOptions, DialogOptions, PromptOptions, SnackbarOptions,
+    SnackbarPlacement, TextFieldOptions,
+};
+
+// 配色演示使用的图片
+const SAMPLE_IMAGE: &str = "https://avatars.githubusercontent.com/u/1?v=4";
 
 #[component]
 pub fn MduiJsApiDemo() -> impl IntoView {
     // 使用现代化的异步 Tree Shaking 支持的 API
     let show_alert = move || {
         spawn_local(async move {
-            if let Err(e) = MduiService::show_alert("提示", "这是通过现代化 Tree Shaking API 调用的对话框！仅加载所需模块！").await {
-                log::error!("Alert error: {:?}", e);
+            let options = AlertOptions::new("提示")
+                .description("这是通过现代化 Tree Shaking API 调用的对话框！仅加载所需模块！")
+                .icon("info");
+            if let Err(e) = MduiService::show_alert(&options).await {
+                log::error!("Alert error: {e}");
             }
         });
     };
 
     let show_confirm = move || {
         spawn_local(async move {
-            match MduiService::show_confirm(
-                "确认操作",
-                "你确定要继续吗？此操作使用了 Tree Shaking 优化。",
-                Some("确定"),
-                Some("取消")
-            ).await {
+            let options = ConfirmOptions::new("确认操作")
+                .description("你确定要继续吗？此操作使用了 Tree Shaking 优化。")
+                .confirm_text("确定")
+                .cancel_text("取消");
+            match MduiService::show_confirm(&options).await {
                 Ok(confirmed) => {
                     let message = if confirmed {
                         "你点击了确定按钮！"
@@ -30,31 +42,135 @@ pub fn MduiJsApiDemo() -> impl IntoView {
                         "你点击了取消按钮。"
                     };
                     
+                    let options = SnackbarOptions::new(message)
+                        .action("知道了")
+                        .auto_close_delay(3000);
                     spawn_local(async move {
-                        if let Err(e) = MduiService::show_snackbar(message, Some("知道了"), Some(3000)).await {
-                            log::error!("Snackbar error: {:?}", e);
+                        if let Err(e) = MduiService::show_snackbar(&options).await {
+                            log::error!("Snackbar error: {e}");
                         }
                     });
                 },
-                Err(e) => log::error!("Confirm error: {:?}", e),
+                Err(e) => log::error!("Confirm error: {e}"),
             }
         });
     };
 
     let show_snackbar = move || {
         spawn_local(async move {
-            if let Err(e) = MduiService::show_snackbar(
-                "这是一个 Snackbar 消息！支持 Tree Shaking 优化。",
-                Some("关闭"),
-                Some(4000)
-            ).await {
-                log::error!("Snackbar error: {:?}", e);
+            let options = SnackbarOptions::new("这是一个 Snackbar 消息！支持 Tree Shaking 优化。")
+                .action("关闭")
+                .placement(SnackbarPlacement::BottomEnd)
+                .auto_close_delay(4000);
+            if let Err(e) = MduiService::show_snackbar(&options).await {
+                log::error!("Snackbar error: {e}");
+            }
+        });
+    };
+
+    let show_prompt = move || {
+        spawn_local(async move {
+            let options = PromptOptions::new("你的名字")
+                .description("输入后点击确定。")
+                .text_field_options(TextFieldOptions::new().value("Leptos").maxlength(20).counter(true));
+            match MduiService::show_prompt(&options).await {
+                Ok(name) => notify(format!("你好，{name}！")),
+                Err(MduiError::Dismissed) => log::debug!("Prompt dismissed"),
+                Err(e) => log::error!("Prompt error: {e}"),
+            }
+        });
+    };
+
+    let show_dialog = move || {
+        spawn_local(async move {
+            let actions = ["稍后", "不再提示", "立即更新"];
+            let options = actions
+                .iter()
+                .fold(DialogOptions::new("发现新版本"), |options, action| options.action(*action))
+                .description("新版本包含性能改进与错误修复。")
+                .icon("system_update");
+            match MduiService::show_dialog(&options).await {
+                Ok(Some(index)) => notify(format!("你选择了「{}」", actions[index])),
+                Ok(None) => notify("对话框已关闭".to_string()),
+                Err(e) => log::error!("Dialog error: {e}"),
+            }
+        });
+    };
+
+    // 主题在亮色、暗色与跟随系统之间切换
+    let theme = RwSignal::new(MduiService::get_theme().unwrap_or_default());
+    let toggle_theme = move || {
+        let next = match theme.get_untracked() {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::Auto,
+            Theme::Auto => Theme::Light,
+        };
+        match MduiService::set_theme(next) {
+            Ok(()) => theme.set(next),
+            Err(e) => log::error!("Set theme error: {e}"),
+        }
+    };
+
+    // 取色结果交给 <ErrorBoundary> 显示，失败时（例如图片跨域）显示错误信息
+    let color_result = RwSignal::new(None::<Result<String, MduiError>>);
+    let color_from_image = move || {
+        spawn_local(async move {
+            let result = async {
+                let color = MduiService::get_color_from_image(SAMPLE_IMAGE).await?;
+                MduiService::set_color_scheme(&color, &ColorSchemeOptions::new().custom_color("brand", "#ff5722"))?;
+                Ok(color)
+            };
+            color_result.set(Some(result.await));
+        });
+    };
+
+    let reset_color_scheme = move || {
+        match MduiService::remove_color_scheme() {
+            Ok(()) => color_result.set(None),
+            Err(e) => log::error!("Remove color scheme error: {e}"),
+        }
+    };
+
+    // 切换界面语言，MDUI 组件内置的文案（如对话框按钮）随之变化
+    let locale = RwSignal::new(MduiService::get_locale().unwrap_or_default());
+    let toggle_locale = move || {
+        spawn_local(async move {
+            let next = if locale.get_untracked() == "zh-cn" { "en-us" } else { "zh-cn" };
+            let result = async {
+                MduiService::load_bundled_locales()?;
+                MduiService::set_locale(next).await
+            };
+            match result.await {
+                Ok(()) => locale.set(next.to_string()),
+                Err(e) => log::error!("Set locale error: {e}"),
             }
         });
     };
 
+    // 监听容器宽度，显示对应的断点
+    let container = NodeRef::<Div>::new();
+    let layout = RwSignal::new(String::new());
+    Effect::new(move |_| {
+        let Some(element) = container.get() else {
+            return;
+        };
+        let observation = MduiService::observe_resize(&element, move |entry| {
+            let width = entry.target().client_width() as f64;
+            if let Ok(query) = MduiService::breakpoint(Some(width)) {
+                layout.set(describe_breakpoint(width, &query));
+            }
+        });
+        match observation {
+            Ok(observation) => {
+                let observation = SendWrapper::new(observation);
+                on_cleanup(move || drop(observation));
+            }
+            Err(e) => log::error!("Observe resize error: {e}"),
+        }
+    });
+
     view! {
-        <div class="js-api-demo">
+        <div class="js-api-demo" node_ref=container>
             <h3>"现代化 MDUI API 演示（Tree Shaking 优化）"</h3>
             
             <div style="margin-bottom: 16px;">
@@ -75,7 +191,53 @@ pub fn MduiJsApiDemo() -> impl IntoView {
                 <MduiButton variant="tonal".to_string() on_click=Box::new(show_snackbar)>
                     "显示消息条"
                 </MduiButton>
+
+                <MduiButton variant="outlined".to_string() on_click=Box::new(show_prompt)>
+                    "显示输入框"
+                </MduiButton>
+
+                <MduiButton variant="outlined".to_string() on_click=Box::new(show_dialog)>
+                    "显示多按钮对话框"
+                </MduiButton>
             </div>
+
+            <h4 style="margin: 24px 0 12px 0; color: #333;">"主题、配色与语言"</h4>
+            <div style="display: flex; gap: 12px; flex-wrap: wrap;">
+                <MduiButton variant="tonal".to_string() icon="dark_mode".to_string() on_click=Box::new(toggle_theme)>
+                    "主题："
+                    {move || theme.get().as_str()}
+                </MduiButton>
+
+                <MduiButton variant="tonal".to_string() icon="palette".to_string() on_click=Box::new(color_from_image)>
+                    "从图片取色"
+                </MduiButton>
+
+                <MduiButton variant="text".to_string() on_click=Box::new(reset_color_scheme)>
+                    "恢复默认配色"
+                </MduiButton>
+
+                <MduiButton variant="tonal".to_string() icon="translate".to_string() on_click=Box::new(toggle_locale)>
+                    "语言："
+                    {move || locale.get()}
+                </MduiButton>
+            </div>
+
+            <ErrorBoundary fallback=|errors| view! {
+                <p style="color: #c0392b; font-size: 14px; margin: 16px 0 0 0;">
+                    {move || errors.get().into_iter().map(|(_, e)| format!("取色失败：{e}")).collect::<Vec<_>>().join("；")}
+                </p>
+            }>
+                {move || color_result.get().map(|result| result.map(|color| view! {
+                    <p style="color: #666; font-size: 14px; margin: 16px 0 0 0;">
+                        {format!("🎨 当前主色：{color}")}
+                    </p>
+                }))}
+            </ErrorBoundary>
+
+            <p style="color: #666; font-size: 14px; margin: 16px 0 0 0;">
+                "📐 "
+                {move || layout.get()}
+            </p>
             
             <div style="margin-top: 24px; padding: 16px; background: #f5f5f5; border-radius: 8px;">
                 <h4 style="margin: 0 0 8px 0; color: #333;">"✨ 技术优势"</h4>
@@ -90,3 +252,33 @@ pub fn MduiJsApiDemo() -> impl IntoView {
         </div>
     }
 }
+
+fn notify(message: String) {
+    spawn_local(async move {
+        if let Err(e) = MduiService::show_snackbar(&SnackbarOptions::new(message).auto_close_delay(3000)).await {
+            log::error!("Snackbar error: {e}");
+        }
+    });
+}
+
+// 用断点查询描述当前布局
+fn describe_breakpoint(width: f64, query: &BreakpointQuery) -> String {
+    let current = Breakpoint::ALL
+        .into_iter()
+        .find(|breakpoint| query.only(*breakpoint))
+        .map(Breakpoint::as_str)
+        .unwrap_or("?");
+    let layout = if query.down(Breakpoint::Md) {
+        "单列布局"
+    } else if query.between(Breakpoint::Md, Breakpoint::Xl) {
+        "双列布局"
+    } else {
+        "三列布局"
+    };
+    let navigation = if query.up(Breakpoint::Lg) && query.not(Breakpoint::Xxl) {
+        "，显示侧边导航"
+    } else {
+        ""
+    };
+    format!("容器宽度 {width}px，断点 {current}：{layout}{navigation}")
+}
@@ -1,5 +1,9 @@
+pub mod about;
 pub mod complex_props_demo;
+pub mod guard_demo;
 pub mod home;
 pub mod not_found;
 pub mod mdui_demo;
 pub mod mdui_js_api_demo;
+pub mod log_viewer;
+pub mod login;
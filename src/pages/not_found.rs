@@ -1,7 +1,70 @@
 use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::hooks::use_location;
+
+use crate::env::use_app_config;
+use crate::features::use_feature_flags;
+use crate::routes::suggest_routes;
 
 /// 404 Not Found Page
+///
+/// 显示请求的路径，并根据已注册的路由给出"你是不是要找"的建议。
+/// 链接使用带 base 的绝对路径，因为该页面也会在其它路由内部作为功能开关的回退显示。
 #[component]
 pub fn NotFound() -> impl IntoView {
-    view! { <h1>"Uh oh!" <br /> "We couldn't find that page!"</h1> }
+    let config = use_app_config();
+    let location = use_location();
+    let flags = use_feature_flags();
+    let base_url = move || config.with(|c| c.base_url.clone());
+
+    // 与路径最相近的页面，列表与"你是不是要找"提示共用同一次计算
+    let suggestions = Memo::new(move |_| {
+        let (base_url, env) = config.with(|c| (c.base_url.clone(), c.env));
+        let pathname = location.pathname.get();
+        let requested = pathname
+            .strip_prefix(base_url.as_str())
+            .unwrap_or(&pathname);
+
+        suggest_routes(requested, env)
+            .into_iter()
+            .filter(|route| route.feature.is_none_or(|name| flags.is_enabled(name)))
+            .map(|route| route.page)
+            .collect::<Vec<_>>()
+    });
+    let suggestion_links = move || {
+        let base_url = base_url();
+        suggestions
+            .get()
+            .into_iter()
+            .map(|page| {
+                let route = page.route();
+                view! {
+                    <li>
+                        <a href=format!("{}{}", base_url, route.path)>
+                            {route.icon} " " {route.title}
+                        </a>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <Title text="Not Found" />
+        <div class="container">
+            <h1>"Uh oh!" <br /> "We couldn't find that page!"</h1>
+            <p>
+                "请求的路径: " <code>{move || location.pathname.get()}</code>
+            </p>
+
+            <Show when=move || suggestions.with(|pages| !pages.is_empty())>
+                <p>"你是不是要找："</p>
+                <ul style="list-style: none; padding: 0;">{suggestion_links}</ul>
+            </Show>
+
+            <p>
+                <a href=base_url>"🏠 返回首页"</a>
+            </p>
+        </div>
+    }
 }
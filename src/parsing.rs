@@ -0,0 +1,80 @@
+//! 构建脚本与运行时共用的解析函数
+//!
+//! `build.rs` 通过 `#[path]` 引入这个文件，因此这里只能使用标准库。
+
+/// 解析 `name,!other` 格式的功能开关列表
+///
+/// `VITE_FEATURE_FLAGS`、`?ff=` 查询参数与 localStorage 中保存的覆盖值都使用这种格式。
+pub fn parse_flags(input: &str) -> impl Iterator<Item = (&str, bool)> + '_ {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| match item.strip_prefix('!') {
+            Some(name) => (name.trim(), false),
+            None => (item, true),
+        })
+}
+
+/// Levenshtein 编辑距离
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_flags_reads_enabled_and_disabled_names() {
+        let flags: Vec<_> = parse_flags("beta, !legacy ,new-nav").collect();
+        assert_eq!(flags, [("beta", true), ("legacy", false), ("new-nav", true)]);
+    }
+
+    #[test]
+    fn parse_flags_skips_empty_items() {
+        assert_eq!(parse_flags("").count(), 0);
+        let flags: Vec<_> = parse_flags(",beta,, ,").collect();
+        assert_eq!(flags, [("beta", true)]);
+    }
+
+    #[test]
+    fn parse_flags_trims_after_negation() {
+        let flags: Vec<_> = parse_flags("! legacy").collect();
+        assert_eq!(flags, [("legacy", false)]);
+    }
+
+    #[test]
+    fn edit_distance_counts_single_edits() {
+        assert_eq!(edit_distance("about", "about"), 0);
+        assert_eq!(edit_distance("abot", "about"), 1);
+        assert_eq!(edit_distance("abouts", "about"), 1);
+        assert_eq!(edit_distance("abuot", "about"), 2);
+        assert_eq!(edit_distance("", "logs"), 4);
+        assert_eq!(edit_distance("logs", ""), 4);
+    }
+
+    #[test]
+    fn edit_distance_is_symmetric_and_char_based() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("sitting", "kitten"), 3);
+        assert_eq!(edit_distance("开发者", "开发"), 1);
+    }
+}
@@ -7,14 +7,39 @@ import 'mdui/components/checkbox';
 import 'mdui/components/icon';
 
 import { alert } from 'mdui/functions/alert';
+import { breakpoint } from 'mdui/functions/breakpoint';
 import { confirm } from 'mdui/functions/confirm';
 import { dialog } from 'mdui/functions/dialog';
+import { getColorFromImage } from 'mdui/functions/getColorFromImage';
+import { getLocale } from 'mdui/functions/getLocale';
+import { getTheme } from 'mdui/functions/getTheme';
+import { loadLocale } from 'mdui/functions/loadLocale';
+import { observeResize } from 'mdui/functions/observeResize';
+import { prompt } from 'mdui/functions/prompt';
+import { removeColorScheme } from 'mdui/functions/removeColorScheme';
+import { setColorScheme } from 'mdui/functions/setColorScheme';
+import { setLocale } from 'mdui/functions/setLocale';
+import { setTheme } from 'mdui/functions/setTheme';
 import { snackbar } from 'mdui/functions/snackbar';
 
 
 export const mdui = {
   alert,
   confirm,
+  prompt,
+  dialog,
+  setTheme,
+  getTheme,
+  setColorScheme,
+  removeColorScheme,
+  getColorFromImage,
+  breakpoint,
+  observeResize,
+  loadLocale,
+  setLocale,
+  getLocale,
+  // 语言包需要由 Vite 处理，Rust 通过它实现 loadLocale 的加载函数
+  importLocale: (locale: string) => import(`mdui/locales/${locale}.js`),
   snackbar: (options: any) => new Promise((resolve, reject) => {
     try {
       resolve(snackbar(options))
@@ -0,0 +1,168 @@
+//! 与查询字符串同步的页面状态
+//!
+//! [`use_query_state`] 把一个可序列化的结构体绑定到当前地址的查询参数上：导航（包括后退/前进）时
+//! 从 URL 读取，信号变化时以替换历史记录的方式写回，方便通过链接分享页面的某个界面状态。
+//! 查询串中不属于该结构体的参数（例如 `?ff=`）会原样保留。
+
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_navigate};
+use leptos_router::NavigateOptions;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// 把 `T` 绑定到查询字符串，返回的信号可以直接读写
+///
+/// 缺失或无法解析的参数回退到 `T::default()`，因此结构体通常需要 `#[serde(default)]`。
+/// 只有信号被修改后才会写入 URL，初次访问页面时地址保持不变。
+pub fn use_query_state<T>() -> RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Default + Clone + PartialEq + Send + Sync + 'static,
+{
+    let location = use_location();
+    let navigate = use_navigate();
+    let state = RwSignal::new(location.search.with_untracked(|search| parse::<T>(search)));
+
+    // URL -> 信号：导航到带不同参数的地址时更新状态
+    Effect::new(move |_| {
+        let value = location.search.with(|search| parse::<T>(search));
+        if state.with_untracked(|current| *current != value) {
+            state.set(value);
+        }
+    });
+
+    // 信号 -> URL：返回值为当前状态序列化后的查询串，供下次比较与移除旧参数
+    Effect::new(move |previous: Option<String>| {
+        let encoded = state.with(encode);
+        let Some(previous) = previous else {
+            return encoded;
+        };
+        if previous == encoded {
+            return encoded;
+        }
+
+        let search = location
+            .search
+            .with_untracked(|search| merge(search, &previous, &encoded));
+        if search != location.search.get_untracked() {
+            let query = if search.is_empty() {
+                String::new()
+            } else {
+                format!("?{search}")
+            };
+            let url = format!(
+                "{}{}{}{}",
+                window().location().origin().unwrap_or_default(),
+                location.pathname.get_untracked(),
+                query,
+                location.hash.get_untracked()
+            );
+            navigate(
+                &url,
+                NavigateOptions {
+                    replace: true,
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+        }
+        encoded
+    });
+
+    state
+}
+
+/// 解析查询串，失败时记录警告并使用默认值
+fn parse<T: DeserializeOwned + Default>(search: &str) -> T {
+    serde_qs::from_str(search.trim_start_matches('?')).unwrap_or_else(|err| {
+        log::warn!("ignoring invalid query state {search:?}: {err}");
+        T::default()
+    })
+}
+
+fn encode<T: Serialize>(value: &T) -> String {
+    serde_qs::to_string(value).unwrap_or_else(|err| {
+        log::error!("failed to encode query state: {err}");
+        String::new()
+    })
+}
+
+/// 用新的状态参数替换查询串中旧的状态参数，保留其他参数的顺序与取值
+fn merge(search: &str, previous: &str, encoded: &str) -> String {
+    let owned_keys = form_urlencoded::parse(previous.as_bytes())
+        .chain(form_urlencoded::parse(encoded.as_bytes()))
+        .map(|(key, _)| key.into_owned())
+        .collect::<Vec<_>>();
+
+    let mut serializer = form_urlencoded::Serializer::new(String::new());
+    for (key, value) in form_urlencoded::parse(search.trim_start_matches('?').as_bytes()) {
+        if !owned_keys.iter().any(|owned| *owned == key) {
+            serializer.append_pair(&key, &value);
+        }
+    }
+    serializer.extend_pairs(form_urlencoded::parse(encoded.as_bytes()));
+    serializer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    struct State {
+        tab: String,
+        page: u32,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn round_trips_through_query_string() {
+        let state = State {
+            tab: "a b&c".to_string(),
+            page: 3,
+            tags: vec!["x".to_string(), "y".to_string()],
+        };
+        assert_eq!(parse::<State>(&format!("?{}", encode(&state))), state);
+    }
+
+    #[test]
+    fn missing_fields_use_defaults() {
+        assert_eq!(
+            parse::<State>("?page=2"),
+            State {
+                page: 2,
+                ..State::default()
+            }
+        );
+        assert_eq!(parse::<State>(""), State::default());
+    }
+
+    #[test]
+    fn invalid_query_falls_back_to_default() {
+        assert_eq!(parse::<State>("?page=many"), State::default());
+    }
+
+    #[test]
+    fn merge_keeps_unrelated_params() {
+        assert_eq!(
+            merge("?ff=beta&tab=a&page=1", "tab=a&page=1", "tab=b&page=1"),
+            "ff=beta&tab=b&page=1"
+        );
+    }
+
+    #[test]
+    fn merge_drops_params_no_longer_encoded() {
+        assert_eq!(
+            merge("?tab=a&page=2&x=1", "tab=a&page=2", "tab=a"),
+            "x=1&tab=a"
+        );
+        assert_eq!(merge("?tab=a", "tab=a", ""), "");
+    }
+
+    #[test]
+    fn merge_adds_state_to_empty_search() {
+        assert_eq!(merge("", "", "tab=b"), "tab=b");
+    }
+}
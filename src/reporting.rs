@@ -0,0 +1,457 @@
+//! 错误上报
+//!
+//! 收集 panic、根部 `<ErrorBoundary>` 捕获的错误与 `log::error!` 记录，附上当前路由、构建信息与
+//! User-Agent，批量 POST 到 [`AppConfig::error_endpoint`](crate::env::AppConfig)。未配置地址时不做任何事。
+//!
+//! - 批量：记录后等待 [`BATCH_DELAY`] 再发送，期间的错误合并为一个请求；
+//! - 重试：请求失败时按指数退避重试，最多 [`MAX_RETRIES`] 次；
+//! - 限流：每分钟最多接收 [`RATE_LIMIT`] 条，超出的只计数，随下一批一起上报丢弃数量；
+//! - 离线队列：待发送的错误保存在 localStorage 中，离线时等待 `online` 事件，页面重新加载后也会继续发送。
+//!
+//! panic 之后 wasm 实例即将终止，无法等待请求完成，因此改用 `navigator.sendBeacon` 立即发送。
+//! 上报器自身的问题只以 warn 级别记录，避免再次触发上报。
+
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{Headers, RequestInit, Response, Storage};
+
+use crate::config;
+use crate::env::{AppEnvironment, BUILD_INFO};
+
+/// 已经单独上报的错误与上报器自身的日志使用的 target，日志器不会再次上报这些记录
+pub const TARGET: &str = "reporting";
+
+/// 离线队列在 localStorage 中的键
+const STORAGE_KEY: &str = "error-reports";
+
+/// 收到错误后等待合并的时间
+const BATCH_DELAY: Duration = Duration::from_secs(2);
+
+/// 每个限流窗口内最多接收的错误数
+const RATE_LIMIT: usize = 20;
+
+/// 限流窗口（毫秒）
+const RATE_WINDOW_MS: f64 = 60_000.0;
+
+/// 队列容量，超出时丢弃最早的记录
+const MAX_QUEUE: usize = 100;
+
+/// 单批请求的最大重试次数，用尽后留在离线队列中等待下次加载
+const MAX_RETRIES: u32 = 5;
+
+/// 重试间隔上限
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+static REPORTER: LazyLock<Mutex<Reporter>> = LazyLock::new(Mutex::default);
+
+/// 错误来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportKind {
+    Panic,
+    Boundary,
+    Log,
+}
+
+/// 一条错误报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorReport {
+    /// 错误编号，与崩溃页面和错误界面上显示的一致
+    pub id: String,
+    pub kind: ReportKind,
+    pub message: String,
+    /// panic 位置或日志 target
+    pub source: Option<String>,
+    /// 发生错误时的地址（路径、查询串与片段）
+    pub route: String,
+    /// 毫秒级 Unix 时间戳
+    pub timestamp: f64,
+    pub env: String,
+    pub build: String,
+    pub user_agent: String,
+}
+
+/// 一次请求的内容
+#[derive(Serialize)]
+struct Batch<'a> {
+    reports: &'a [ErrorReport],
+    /// 因限流被丢弃的错误数
+    dropped: usize,
+}
+
+#[derive(Default)]
+struct Reporter {
+    /// `None` 表示尚未初始化或未配置上报地址
+    endpoint: Option<String>,
+    /// 运行时配置中的部署环境，初始化之前使用编译时配置
+    env: Option<AppEnvironment>,
+    initialized: bool,
+    queue: Vec<ErrorReport>,
+    dropped: usize,
+    window_start: f64,
+    window_count: usize,
+    flush_scheduled: bool,
+    in_flight: bool,
+    retries: u32,
+}
+
+impl Reporter {
+    /// 限流检查，返回是否接收这条错误
+    fn admit(&mut self, now: f64) -> bool {
+        if now - self.window_start >= RATE_WINDOW_MS {
+            self.window_start = now;
+            self.window_count = 0;
+        }
+        if self.window_count >= RATE_LIMIT {
+            self.dropped += 1;
+            return false;
+        }
+        self.window_count += 1;
+        true
+    }
+
+    fn push(&mut self, report: ErrorReport) {
+        if self.queue.len() == MAX_QUEUE {
+            self.queue.remove(0);
+            self.dropped += 1;
+        }
+        self.queue.push(report);
+        // 初始化之前不写入 localStorage，否则会覆盖上次保存、尚未读取的离线队列
+        if self.initialized {
+            self.persist();
+        }
+    }
+
+    fn persist(&self) {
+        let Some(storage) = storage() else {
+            return;
+        };
+        if self.queue.is_empty() {
+            let _ = storage.remove_item(STORAGE_KEY);
+        } else if let Ok(json) = serde_json::to_string(&self.queue) {
+            let _ = storage.set_item(STORAGE_KEY, &json);
+        }
+    }
+
+    fn body(&self) -> Option<String> {
+        serde_json::to_string(&Batch {
+            reports: &self.queue,
+            dropped: self.dropped,
+        })
+        .ok()
+    }
+}
+
+/// 初始化上报器，加载运行时配置后调用
+///
+/// `env` 为运行时配置中的部署环境，随每条报告一起发送。
+/// 初始化之前记录的错误会暂存，配置了上报地址时随第一批发送，否则丢弃。
+/// 上报器在页面中只有一个，挂载多个应用实例时以第一次初始化为准。
+pub fn init(endpoint: Option<String>, env: AppEnvironment) {
+    if REPORTER.lock().unwrap().initialized {
+        return;
+    }
+    let Some(endpoint) = endpoint.filter(|endpoint| !endpoint.is_empty()) else {
+        let mut reporter = REPORTER.lock().unwrap();
+        reporter.env = Some(env);
+        reporter.initialized = true;
+        reporter.queue.clear();
+        reporter.persist();
+        return;
+    };
+
+    {
+        let mut reporter = REPORTER.lock().unwrap();
+        // 暂存的错误记录时还没有运行时配置
+        for report in &mut reporter.queue {
+            report.env = env.to_string();
+        }
+        let pending = std::mem::take(&mut reporter.queue);
+        reporter.queue = merge_stored(load_stored(), pending);
+        reporter.endpoint = Some(endpoint);
+        reporter.env = Some(env);
+        reporter.initialized = true;
+        reporter.persist();
+    }
+
+    // 恢复网络后立即发送积压的错误
+    let _ = window_event_listener(ev::online, |_| flush());
+    schedule(Duration::ZERO);
+}
+
+/// 记录一条错误
+///
+/// panic 会立即通过 `sendBeacon` 发送，其他错误在 [`BATCH_DELAY`] 后批量发送。
+pub fn report(id: String, kind: ReportKind, message: String, source: Option<String>) {
+    // 上报器内部出错时不再重入
+    let Ok(mut reporter) = REPORTER.try_lock() else {
+        return;
+    };
+    if reporter.initialized && reporter.endpoint.is_none() {
+        return;
+    }
+
+    let now = js_sys::Date::now();
+    if !reporter.admit(now) {
+        return;
+    }
+    let env = reporter.env.unwrap_or(config::ENV);
+    reporter.push(ErrorReport {
+        id,
+        kind,
+        message,
+        source,
+        route: current_route(),
+        timestamp: now,
+        env: env.to_string(),
+        build: BUILD_INFO.to_string(),
+        user_agent: window().navigator().user_agent().unwrap_or_default(),
+    });
+
+    if kind == ReportKind::Panic {
+        send_beacon(&mut reporter);
+    } else if reporter.endpoint.is_some() {
+        drop(reporter);
+        schedule(BATCH_DELAY);
+    }
+}
+
+fn schedule(delay: Duration) {
+    {
+        let mut reporter = REPORTER.lock().unwrap();
+        if reporter.flush_scheduled {
+            return;
+        }
+        reporter.flush_scheduled = true;
+    }
+    set_timeout(flush, delay);
+}
+
+/// 发送队列中的全部错误
+fn flush() {
+    let (endpoint, body, count, dropped) = {
+        let mut reporter = REPORTER.lock().unwrap();
+        reporter.flush_scheduled = false;
+        let Some(endpoint) = reporter.endpoint.clone() else {
+            return;
+        };
+        if reporter.in_flight || reporter.queue.is_empty() || !is_online() {
+            return;
+        }
+        let Some(body) = reporter.body() else {
+            return;
+        };
+        reporter.in_flight = true;
+        (endpoint, body, reporter.queue.len(), reporter.dropped)
+    };
+
+    spawn_local(async move {
+        let result = post(&endpoint, &body).await;
+
+        let retry = {
+            let mut reporter = REPORTER.lock().unwrap();
+            reporter.in_flight = false;
+            match &result {
+                Ok(()) => {
+                    // 发送期间新增的错误仍留在队列中
+                    let sent = count.min(reporter.queue.len());
+                    reporter.queue.drain(..sent);
+                    reporter.dropped = reporter.dropped.saturating_sub(dropped);
+                    reporter.retries = 0;
+                    reporter.persist();
+                    (!reporter.queue.is_empty()).then_some(BATCH_DELAY)
+                }
+                Err(_) if reporter.retries < MAX_RETRIES => {
+                    reporter.retries += 1;
+                    Some(backoff(reporter.retries))
+                }
+                Err(_) => {
+                    reporter.retries = 0;
+                    None
+                }
+            }
+        };
+
+        if let Err(err) = result {
+            log::warn!(target: TARGET, "failed to send {count} error report(s): {err:?}");
+        }
+        if let Some(delay) = retry {
+            schedule(delay);
+        }
+    });
+}
+
+/// panic 后立即发送，成功交给浏览器后清空队列
+fn send_beacon(reporter: &mut Reporter) {
+    let Some(endpoint) = reporter.endpoint.clone() else {
+        return;
+    };
+    let Some(body) = reporter.body() else {
+        return;
+    };
+    if window()
+        .navigator()
+        .send_beacon_with_opt_str(&endpoint, Some(&body))
+        .unwrap_or(false)
+    {
+        reporter.queue.clear();
+        reporter.dropped = 0;
+        reporter.persist();
+    }
+}
+
+async fn post(endpoint: &str, body: &str) -> Result<(), JsValue> {
+    let headers = Headers::new()?;
+    headers.set("Content-Type", "application/json")?;
+
+    let init = RequestInit::new();
+    init.set_method("POST");
+    init.set_headers(&headers);
+    init.set_body(&JsValue::from_str(body));
+
+    let response: Response = JsFuture::from(window().fetch_with_str_and_init(endpoint, &init))
+        .await?
+        .dyn_into()?;
+    if !response.ok() {
+        return Err(format!("HTTP {}", response.status()).into());
+    }
+    Ok(())
+}
+
+/// 第 `attempt` 次重试前的等待时间：2s、4s、8s…，不超过 [`MAX_BACKOFF`]
+fn backoff(attempt: u32) -> Duration {
+    BATCH_DELAY
+        .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+        .min(MAX_BACKOFF)
+}
+
+/// 合并上次保存的离线队列与初始化之前暂存的错误
+///
+/// 上次未发送成功的错误排在前面；已经在离线队列中的错误（按编号判断）不再重复加入，
+/// 超出 [`MAX_QUEUE`] 时丢弃最早的记录。
+fn merge_stored(mut stored: Vec<ErrorReport>, pending: Vec<ErrorReport>) -> Vec<ErrorReport> {
+    for report in pending {
+        if !stored.iter().any(|existing| existing.id == report.id) {
+            stored.push(report);
+        }
+    }
+    let overflow = stored.len().saturating_sub(MAX_QUEUE);
+    stored.drain(..overflow);
+    stored
+}
+
+fn current_route() -> String {
+    let location = window().location();
+    format!(
+        "{}{}{}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default(),
+        location.hash().unwrap_or_default()
+    )
+}
+
+fn is_online() -> bool {
+    window().navigator().on_line()
+}
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+fn load_stored() -> Vec<ErrorReport> {
+    storage()
+        .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_report(id: &str) -> ErrorReport {
+        ErrorReport {
+            id: id.to_string(),
+            kind: ReportKind::Log,
+            message: "boom".to_string(),
+            source: None,
+            route: "/".to_string(),
+            timestamp: 0.0,
+            env: "production".to_string(),
+            build: "test".to_string(),
+            user_agent: String::new(),
+        }
+    }
+
+    fn ids(reports: &[ErrorReport]) -> Vec<&str> {
+        reports.iter().map(|report| report.id.as_str()).collect()
+    }
+
+    #[test]
+    fn reports_before_init_stay_in_memory() {
+        // 未初始化时 push 不访问 localStorage（在非 wasm 环境中访问会 panic）
+        let mut reporter = Reporter::default();
+        reporter.push(error_report("early"));
+        assert_eq!(ids(&reporter.queue), ["early"]);
+    }
+
+    #[test]
+    fn init_keeps_stored_queue_and_adds_pending_once() {
+        let stored = vec![error_report("old-1"), error_report("old-2")];
+        let pending = vec![error_report("old-2"), error_report("early")];
+        assert_eq!(
+            ids(&merge_stored(stored, pending)),
+            ["old-1", "old-2", "early"]
+        );
+    }
+
+    #[test]
+    fn merged_queue_is_capped() {
+        let stored = (0..MAX_QUEUE)
+            .map(|i| error_report(&i.to_string()))
+            .collect();
+        let merged = merge_stored(stored, vec![error_report("early")]);
+        assert_eq!(merged.len(), MAX_QUEUE);
+        assert_eq!(merged[0].id, "1");
+        assert_eq!(merged[MAX_QUEUE - 1].id, "early");
+    }
+
+    #[test]
+    fn backoff_doubles_from_batch_delay() {
+        assert_eq!(backoff(1), BATCH_DELAY);
+        assert_eq!(backoff(2), Duration::from_secs(4));
+        assert_eq!(backoff(3), Duration::from_secs(8));
+        assert_eq!(backoff(5), Duration::from_secs(32));
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        assert_eq!(backoff(6), MAX_BACKOFF);
+        assert_eq!(backoff(MAX_RETRIES + 10), MAX_BACKOFF);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn backoff_treats_zero_as_first_attempt() {
+        assert_eq!(backoff(0), BATCH_DELAY);
+    }
+
+    #[test]
+    fn rate_limit_counts_dropped_reports() {
+        let mut reporter = Reporter::default();
+        for _ in 0..RATE_LIMIT {
+            assert!(reporter.admit(1_000.0));
+        }
+        assert!(!reporter.admit(1_000.0));
+        assert_eq!(reporter.dropped, 1);
+        // 新的窗口重新计数
+        assert!(reporter.admit(1_000.0 + RATE_WINDOW_MS));
+    }
+}
@@ -0,0 +1,582 @@
+//! 路由表
+//!
+//! 所有页面只在 [`ROUTES`] 中登记一次，`App` 由它生成 `<Routes>`、导航栏与页面头部信息。
+//! 路由按 [`RouteGroup`] 嵌套在对应的布局中：演示页面位于带侧边栏的 `DemosLayout` 内。
+//! 路径一律写成相对于 `<Router base>` 的形式（不带前导 `/`），这样在 GitHub Pages
+//! 等子路径部署下链接同样有效。
+//!
+//! 演示页面通过 [`PageView::Lazy`] 懒加载：启用 `split` feature 时它们会被拆分到独立的
+//! wasm 模块中，首次访问（或悬停在链接上预取）时才下载；未启用时行为与普通页面相同。
+
+use std::future::Future;
+use std::pin::Pin;
+
+#[cfg(feature = "split")]
+use leptos::lazy;
+use leptos::prelude::*;
+use leptos::tachys::view::iterators::StaticVec;
+use leptos::task::spawn_local;
+use leptos_meta::{Link, Meta, Title};
+use leptos_router::any_nested_route::{AnyNestedRoute, IntoAnyNestedRoute};
+use leptos_router::{ChooseView, NestedRoute, StaticSegment, WildcardSegment};
+use send_wrapper::SendWrapper;
+
+use crate::components::feature::{Fallback, Feature};
+use crate::components::protected_route::ProtectedRoute;
+use crate::debug::track_owner;
+use crate::env::{use_app_config, AppEnvironment, RouterMode};
+use crate::layouts::app_shell::HeroVisibility;
+use crate::pages::about::About;
+use crate::pages::complex_props_demo::ComplexPropsDemo;
+use crate::pages::guard_demo::{DevOnlyDemo, MembersDemo};
+use crate::pages::home::Home;
+use crate::pages::log_viewer::LogViewer;
+use crate::pages::login::Login;
+use crate::pages::mdui_demo::MduiDemo;
+use crate::pages::mdui_js_api_demo::MduiJsApiDemo;
+use crate::pages::not_found::NotFound;
+use crate::parsing::edit_distance;
+use crate::transitions::{self, use_transition_root, use_transitions, TransitionConfig};
+
+/// 页面标识，用于在代码中引用路由而不是重复书写路径
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Home,
+    About,
+    ComplexPropsDemo,
+    MduiDemo,
+    MduiJsApiDemo,
+    MembersDemo,
+    DevOnlyDemo,
+    Logs,
+    Login,
+    /// 未匹配任何路由时的 404 页面，定义在 [`NOT_FOUND`] 而不是路由表中
+    NotFound,
+}
+
+/// 路由分组，决定路由在哪些部署环境中注册
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteGroup {
+    /// 所有环境
+    Core,
+    /// 演示页面，见 [`AppEnvironment::registers_demo_routes`]
+    Demo,
+    /// 开发者工具，见 [`AppEnvironment::mounts_dev_tools`]
+    Dev,
+}
+
+impl RouteGroup {
+    /// 该分组是否在指定环境中注册
+    pub fn is_registered(self, env: AppEnvironment) -> bool {
+        match self {
+            Self::Core => true,
+            Self::Demo => env.registers_demo_routes(),
+            Self::Dev => env.mounts_dev_tools(),
+        }
+    }
+}
+
+/// 路由守卫
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Guard {
+    /// 所有人可访问
+    Public,
+    /// 需要登录
+    Authenticated,
+    /// 需要登录且拥有指定角色
+    Role(&'static str),
+}
+
+/// 异步加载的页面视图
+///
+/// 浏览器中只有一个线程，用 [`SendWrapper`] 满足 `Suspend` 对 `Send` 的要求。
+pub type PageFuture = Pin<Box<dyn Future<Output = AnyView> + Send>>;
+
+/// 页面视图的加载方式
+#[derive(Debug, Clone, Copy)]
+pub enum PageView {
+    /// 与主模块一起加载
+    Eager(fn() -> AnyView),
+    /// 首次访问时加载
+    Lazy {
+        load: fn() -> PageFuture,
+        /// 只下载页面所在的模块而不渲染，用于悬停预取
+        preload: fn() -> Pin<Box<dyn Future<Output = ()> + Send>>,
+    },
+}
+
+/// 一条路由定义
+#[derive(Debug)]
+pub struct RouteDef {
+    pub page: Page,
+    /// 相对于 `<Router base>` 的路径，首页为空字符串
+    pub path: &'static str,
+    pub view: PageView,
+    /// 页面标题，同时用作导航文字
+    pub title: &'static str,
+    /// 页面描述，输出为 `<meta name="description">`
+    pub description: &'static str,
+    pub icon: &'static str,
+    /// 是否显示在导航栏中
+    pub in_nav: bool,
+    /// 导航栏中的排序，越小越靠前
+    pub order: i32,
+    pub group: RouteGroup,
+    /// 是否显示外壳布局的标题区
+    pub hero: bool,
+    pub guard: Guard,
+    /// 控制该页面的功能开关，关闭时导航隐藏、访问显示 404
+    pub feature: Option<&'static str>,
+}
+
+/// 路由表
+pub const ROUTES: &[RouteDef] = &[
+    RouteDef {
+        page: Page::Home,
+        path: "",
+        view: PageView::Eager(|| Home().into_any()),
+        title: "Home",
+        description: "Leptos + Vite 模板首页",
+        icon: "🏠",
+        in_nav: true,
+        order: 0,
+        group: RouteGroup::Core,
+        hero: true,
+        guard: Guard::Public,
+        feature: None,
+    },
+    RouteDef {
+        page: Page::ComplexPropsDemo,
+        path: "complex-props-demo",
+        view: PageView::Lazy {
+            load: || Box::pin(SendWrapper::new(load_complex_props_demo())),
+            preload: || Box::pin(SendWrapper::new(preload_complex_props_demo())),
+        },
+        title: "Complex Props Demo",
+        description: "结构体、枚举与回调等复杂 Props 的用法演示",
+        icon: "🧩",
+        in_nav: true,
+        order: 10,
+        group: RouteGroup::Demo,
+        hero: false,
+        guard: Guard::Public,
+        feature: None,
+    },
+    RouteDef {
+        page: Page::MduiDemo,
+        path: "md-demo",
+        view: PageView::Lazy {
+            load: || Box::pin(SendWrapper::new(load_mdui_demo())),
+            preload: || Box::pin(SendWrapper::new(preload_mdui_demo())),
+        },
+        title: "Mdui Demo",
+        description: "MDUI Web Components 与 Leptos 集成演示",
+        icon: "🎨",
+        in_nav: true,
+        order: 20,
+        group: RouteGroup::Demo,
+        hero: true,
+        guard: Guard::Public,
+        feature: None,
+    },
+    RouteDef {
+        page: Page::MduiJsApiDemo,
+        path: "md-js-api-demo",
+        view: PageView::Lazy {
+            load: || Box::pin(SendWrapper::new(load_mdui_js_api_demo())),
+            preload: || Box::pin(SendWrapper::new(preload_mdui_js_api_demo())),
+        },
+        title: "Mdui Js Api Demo",
+        description: "通过 wasm-bindgen 调用 MDUI JavaScript API 的演示",
+        icon: "⚙️",
+        in_nav: true,
+        order: 30,
+        group: RouteGroup::Demo,
+        hero: true,
+        guard: Guard::Public,
+        feature: Some("md-js-api-demo"),
+    },
+    RouteDef {
+        page: Page::MembersDemo,
+        path: "members-demo",
+        view: PageView::Lazy {
+            load: || Box::pin(SendWrapper::new(load_members_demo())),
+            preload: || Box::pin(SendWrapper::new(preload_members_demo())),
+        },
+        title: "Members Demo",
+        description: "需要登录才能访问的页面，演示路由守卫",
+        icon: "👥",
+        in_nav: true,
+        order: 40,
+        group: RouteGroup::Demo,
+        hero: false,
+        guard: Guard::Authenticated,
+        feature: None,
+    },
+    RouteDef {
+        page: Page::DevOnlyDemo,
+        path: "dev-only-demo",
+        view: PageView::Lazy {
+            load: || Box::pin(SendWrapper::new(load_dev_only_demo())),
+            preload: || Box::pin(SendWrapper::new(preload_dev_only_demo())),
+        },
+        title: "Dev Only Demo",
+        description: "需要开发者角色才能访问的页面，演示按角色的路由守卫",
+        icon: "🛠️",
+        in_nav: true,
+        order: 50,
+        group: RouteGroup::Demo,
+        hero: false,
+        guard: Guard::Role("开发者"),
+        feature: None,
+    },
+    RouteDef {
+        page: Page::About,
+        path: "about",
+        view: PageView::Eager(|| About().into_any()),
+        title: "About",
+        description: "版本号、提交与构建时间等构建信息",
+        icon: "ℹ️",
+        in_nav: true,
+        order: 90,
+        group: RouteGroup::Core,
+        hero: true,
+        guard: Guard::Public,
+        feature: None,
+    },
+    RouteDef {
+        page: Page::Logs,
+        path: "logs",
+        view: PageView::Eager(|| LogViewer().into_any()),
+        title: "Logs",
+        description: "开发环境日志查看器",
+        icon: "📜",
+        in_nav: false,
+        order: 100,
+        group: RouteGroup::Dev,
+        hero: false,
+        guard: Guard::Public,
+        feature: None,
+    },
+    RouteDef {
+        page: Page::Login,
+        path: "login",
+        view: PageView::Eager(|| Login().into_any()),
+        title: "Login",
+        description: "登录以访问受保护的页面",
+        icon: "🔑",
+        in_nav: false,
+        order: 110,
+        group: RouteGroup::Core,
+        hero: false,
+        guard: Guard::Public,
+        feature: None,
+    },
+];
+
+/// 404 页面的路由定义
+///
+/// 不在 [`ROUTES`] 中：它由 [`NotFoundRoute`] 以通配路径注册在外壳布局的最后，
+/// 不参与导航与路由建议，但和其它页面一样经过 [`render`] 设置页面头部与标题区。
+pub const NOT_FOUND: RouteDef = RouteDef {
+    page: Page::NotFound,
+    path: "",
+    view: PageView::Eager(|| NotFound().into_any()),
+    title: "Not Found",
+    description: "请求的页面不存在",
+    icon: "❓",
+    in_nav: false,
+    order: i32::MAX,
+    group: RouteGroup::Core,
+    hero: false,
+    guard: Guard::Public,
+    feature: None,
+};
+
+// 每个懒加载页面需要独立的函数：`#[lazy]` 按函数名与位置划分 wasm 模块，
+// 并生成 `__preload_<函数名>` 预取函数。
+
+#[cfg_attr(feature = "split", lazy)]
+async fn load_complex_props_demo() -> AnyView {
+    ComplexPropsDemo().into_any()
+}
+
+async fn preload_complex_props_demo() {
+    #[cfg(feature = "split")]
+    __preload_load_complex_props_demo().await;
+}
+
+#[cfg_attr(feature = "split", lazy)]
+async fn load_mdui_demo() -> AnyView {
+    MduiDemo().into_any()
+}
+
+async fn preload_mdui_demo() {
+    #[cfg(feature = "split")]
+    __preload_load_mdui_demo().await;
+}
+
+#[cfg_attr(feature = "split", lazy)]
+async fn load_mdui_js_api_demo() -> AnyView {
+    MduiJsApiDemo().into_any()
+}
+
+async fn preload_mdui_js_api_demo() {
+    #[cfg(feature = "split")]
+    __preload_load_mdui_js_api_demo().await;
+}
+
+#[cfg_attr(feature = "split", lazy)]
+async fn load_members_demo() -> AnyView {
+    MembersDemo().into_any()
+}
+
+async fn preload_members_demo() {
+    #[cfg(feature = "split")]
+    __preload_load_members_demo().await;
+}
+
+#[cfg_attr(feature = "split", lazy)]
+async fn load_dev_only_demo() -> AnyView {
+    DevOnlyDemo().into_any()
+}
+
+async fn preload_dev_only_demo() {
+    #[cfg(feature = "split")]
+    __preload_load_dev_only_demo().await;
+}
+
+impl RouteDef {
+    /// 在后台预取懒加载页面的模块，普通页面不做任何事
+    pub fn prefetch(&self) {
+        if let PageView::Lazy { preload, .. } = self.view {
+            spawn_local(preload());
+        }
+    }
+}
+
+impl Page {
+    /// 页面的路由定义
+    pub fn route(self) -> &'static RouteDef {
+        if self == Page::NotFound {
+            return &NOT_FOUND;
+        }
+        ROUTES
+            .iter()
+            .find(|route| route.page == self)
+            .expect("every page has a route definition")
+    }
+
+    /// 相对于 `<Router base>` 的链接地址
+    pub fn href(self) -> &'static str {
+        self.route().path
+    }
+
+    /// 页面路由是否在指定环境中注册
+    pub fn is_registered(self, env: AppEnvironment) -> bool {
+        self.route().group.is_registered(env)
+    }
+}
+
+/// 在指定环境中注册的路由
+pub fn registered_routes(env: AppEnvironment) -> impl Iterator<Item = &'static RouteDef> {
+    ROUTES
+        .iter()
+        .filter(move |route| route.group.is_registered(env))
+}
+
+/// 导航栏中的路由，按 `order` 排序
+pub fn nav_routes(env: AppEnvironment) -> Vec<&'static RouteDef> {
+    let mut routes: Vec<_> = registered_routes(env)
+        .filter(|route| route.in_nav)
+        .collect();
+    routes.sort_by_key(|route| route.order);
+    routes
+}
+
+/// 与未知路径最相近的已注册路由，按相似度排序
+///
+/// `requested` 为相对于 `<Router base>` 的路径，首尾的 `/` 会被忽略。
+pub fn suggest_routes(requested: &str, env: AppEnvironment) -> Vec<&'static RouteDef> {
+    const MAX_SUGGESTIONS: usize = 3;
+
+    let requested = requested.trim_matches('/').to_lowercase();
+    if requested.is_empty() {
+        return Vec::new();
+    }
+    // 允许的编辑距离随路径长度增长，短路径至少允许 2 处差异
+    let threshold = (requested.chars().count() / 3).max(2);
+
+    let mut candidates: Vec<_> = registered_routes(env)
+        .filter(|route| !route.path.is_empty())
+        .filter_map(|route| {
+            let distance = if route.path.contains(&requested) || requested.contains(route.path) {
+                0
+            } else {
+                edit_distance(&requested, route.path)
+            };
+            (distance <= threshold).then_some((distance, route))
+        })
+        .collect();
+    candidates.sort_by_key(|(distance, route)| (*distance, route.order));
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, route)| route)
+        .collect()
+}
+
+/// 由路由表生成的某个分组的路由，按布局嵌套在对应的父路由中
+#[component(transparent)]
+pub fn GroupRoutes(env: AppEnvironment, group: RouteGroup) -> StaticVec<AnyNestedRoute> {
+    registered_routes(env)
+        .filter(|route| route.group == group)
+        .map(|route| {
+            NestedRoute::new(StaticSegment(route.path), RouteView(route)).into_any_nested_route()
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// 404 页面的通配路由，放在外壳布局中所有路由之后
+#[component(transparent)]
+pub fn NotFoundRoute() -> AnyNestedRoute {
+    NestedRoute::new(WildcardSegment("any"), RouteView(&NOT_FOUND)).into_any_nested_route()
+}
+
+/// 路由的视图：懒加载页面在模块下载完成前，路由器会保持显示上一个页面
+#[derive(Debug, Clone, Copy)]
+struct RouteView(&'static RouteDef);
+
+impl ChooseView for RouteView {
+    async fn choose(self) -> AnyView {
+        let transition = use_transitions();
+        let root = use_transition_root();
+        // 先下载页面所在的模块，之后渲染时不再需要等待
+        self.preload().await;
+        // 模块就绪后再让旧页面离场，避免下载期间显示空白
+        transitions::leave(transition, root).await;
+        render(self.0, transition)
+    }
+
+    async fn preload(&self) {
+        if let PageView::Lazy { preload, .. } = self.0.view {
+            preload().await;
+        }
+    }
+}
+
+/// 渲染页面：设置页面头部与标题区，套上路由守卫，并按功能开关决定显示页面还是 404
+fn render(route: &'static RouteDef, transition: TransitionConfig) -> AnyView {
+    if let Some(HeroVisibility(hero)) = use_context() {
+        hero.set(route.hero);
+    }
+
+    // 在页面实际渲染的位置登记，守卫与功能开关内部的 Owner 也会一并计入
+    let page = move || {
+        track_owner();
+        match route.view {
+            PageView::Eager(view) => view(),
+            PageView::Lazy { load, .. } => Suspend::new(load()).into_any(),
+        }
+    };
+    let page = move || match route.guard {
+        Guard::Public => page(),
+        Guard::Authenticated => view! { <ProtectedRoute>{page()}</ProtectedRoute> }.into_any(),
+        Guard::Role(role) => {
+            view! { <ProtectedRoute role=role>{page()}</ProtectedRoute> }.into_any()
+        }
+    };
+
+    let content = match route.feature {
+        Some(name) => view! {
+            <Feature name=name>
+                <Fallback slot>
+                    <NotFound />
+                </Fallback>
+                {page()}
+            </Feature>
+        }
+        .into_any(),
+        None => page(),
+    };
+
+    let (class, style) = transitions::enter_class(&transition);
+    view! {
+        <PageHead route />
+        <div class=class style=style>
+            {content}
+        </div>
+    }
+    .into_any()
+}
+
+/// 页面的 `<head>` 元素：标题（由 `App` 中的格式化函数补全应用名）、描述与 canonical 链接
+///
+/// 404 页面没有对应的地址，不输出 canonical 链接。
+#[component]
+fn PageHead(route: &'static RouteDef) -> impl IntoView {
+    let canonical = (route.page != Page::NotFound).then(|| {
+        let origin = window().location().origin().unwrap_or_default();
+        // hash 模式下地址栏显示的是 `{base}#/{path}`，canonical 链接与之保持一致
+        let href = use_app_config().with_untracked(|c| match c.router_mode {
+            RouterMode::Hash => format!("{}{}#/{}", origin, c.base_url, route.path),
+            RouterMode::History => format!("{}{}{}", origin, c.base_url, route.path),
+        });
+        view! { <Link rel="canonical" href=href /> }
+    });
+
+    view! {
+        <Title text=route.title />
+        <Meta name="description" content=route.description />
+        {canonical}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggested(requested: &str, env: AppEnvironment) -> Vec<Page> {
+        suggest_routes(requested, env)
+            .into_iter()
+            .map(|route| route.page)
+            .collect()
+    }
+
+    #[test]
+    fn suggests_routes_with_typos() {
+        assert_eq!(
+            suggested("/abot", AppEnvironment::Production),
+            [Page::About]
+        );
+        assert_eq!(
+            suggested("LOGIN/", AppEnvironment::Production),
+            [Page::Login]
+        );
+    }
+
+    #[test]
+    fn prefers_closer_routes_then_nav_order() {
+        assert_eq!(
+            suggested("md", AppEnvironment::Development),
+            [Page::MduiDemo, Page::MduiJsApiDemo]
+        );
+    }
+
+    #[test]
+    fn only_suggests_registered_routes() {
+        assert_eq!(
+            suggested("logs", AppEnvironment::Development),
+            [Page::Logs, Page::Login]
+        );
+        assert_eq!(suggested("logs", AppEnvironment::Production), [Page::Login]);
+        assert!(suggested("md-demo", AppEnvironment::Production).is_empty());
+    }
+
+    #[test]
+    fn ignores_empty_and_unrelated_paths() {
+        assert!(suggested("/", AppEnvironment::Development).is_empty());
+        assert!(suggested("xyzzy-plugh", AppEnvironment::Development).is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+//! GitHub Pages 的 SPA 重定向还原
+//!
+//! 静态托管找不到 `/{base}md-demo` 时返回 `404.html`（由 `scripts/spa-fallback.js` 生成），
+//! 它把原始路径编码进 `?__path=` 后跳转回应用根路径。这里在挂载应用前解码该参数，
+//! 用 `history.replaceState` 还原真实地址，路由器读取到的就是用户最初访问的页面。
+
+use wasm_bindgen::JsValue;
+use web_sys::UrlSearchParams;
+
+/// 查询参数名，与 `scripts/spa-fallback.js` 中的 `PARAM` 保持一致
+pub const PARAM: &str = "__path";
+
+/// 还原被 `404.html` 重定向的地址，没有重定向参数时不做任何事
+///
+/// 参数值是相对于 `base_url` 的路径（可带查询串与片段），还原后的地址始终位于 `base_url` 之下。
+pub fn restore(base_url: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(path) = window
+        .location()
+        .search()
+        .ok()
+        .and_then(|search| UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get(PARAM))
+    else {
+        return;
+    };
+
+    let url = format!("{base_url}{}", path.trim_start_matches('/'));
+    match window
+        .history()
+        .and_then(|history| history.replace_state_with_url(&JsValue::NULL, "", Some(&url)))
+    {
+        Ok(()) => log::debug!("restored redirected path {url}"),
+        Err(err) => log::warn!("failed to restore redirected path {url:?}: {err:?}"),
+    }
+}
@@ -0,0 +1,164 @@
+//! 路由切换动画
+//!
+//! 每个页面渲染在 `.route-page` 容器中。切换路由时，[`leave`] 先给当前实例中仍在显示的旧页面加上离场动画并等待其结束，
+//! 新页面渲染时再由 [`enter_class`] 加上入场动画。动画本身由 `styles.css` 中的
+//! `.route-enter-*` / `.route-leave-*` 定义，时长通过 CSS 变量 `--route-transition-duration` 传入。
+//! 用户开启 `prefers-reduced-motion` 时两者都会跳过。
+//!
+//! 配置来自 `config.json` 的 `transitions` 字段，例如 `{ "enter": "none", "leave": "fade", "durationMs": 200 }`。
+
+use std::time::Duration;
+
+use leptos::html::Div;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlElement;
+
+/// 页面容器的 class
+pub const PAGE_CLASS: &str = "route-page";
+
+/// 动画效果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Transition {
+    /// 不使用动画
+    None,
+    /// 淡入淡出
+    #[default]
+    Fade,
+    /// 淡入淡出并上下滑动
+    Slide,
+}
+
+impl Transition {
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Fade => Some("fade"),
+            Self::Slide => Some("slide"),
+        }
+    }
+}
+
+/// 路由切换动画配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TransitionConfig {
+    /// 新页面的入场动画
+    pub enter: Transition,
+    /// 旧页面的离场动画，新页面会在其结束后才显示
+    pub leave: Transition,
+    /// 单个动画的时长（毫秒）
+    pub duration_ms: u32,
+}
+
+impl Default for TransitionConfig {
+    fn default() -> Self {
+        Self {
+            enter: Transition::Slide,
+            leave: Transition::Fade,
+            duration_ms: 150,
+        }
+    }
+}
+
+impl TransitionConfig {
+    fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration_ms.into())
+    }
+
+    /// `--route-transition-duration` 的取值
+    fn duration_value(&self) -> String {
+        format!("{}ms", self.duration_ms)
+    }
+}
+
+/// 应用实例的根元素，离场动画只作用于其中的页面
+///
+/// 同一页面挂载多个实例时，一个实例切换路由不会影响其他实例。
+#[derive(Debug, Clone, Copy)]
+pub struct TransitionRoot(pub NodeRef<Div>);
+
+/// 在上下文中提供路由切换动画配置与实例的根元素
+pub fn provide_transitions(config: TransitionConfig, root: NodeRef<Div>) {
+    provide_context(config);
+    provide_context(TransitionRoot(root));
+}
+
+/// 获取路由切换动画配置，未提供时使用默认配置
+pub fn use_transitions() -> TransitionConfig {
+    use_context().unwrap_or_default()
+}
+
+/// 获取实例的根元素，未提供时离场动画不做任何事
+pub fn use_transition_root() -> Option<TransitionRoot> {
+    use_context()
+}
+
+/// 用户是否要求减少动画
+pub fn prefers_reduced_motion() -> bool {
+    window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+/// 新页面容器的 class 与 style
+pub fn enter_class(config: &TransitionConfig) -> (String, String) {
+    match config.enter.name().filter(|_| !prefers_reduced_motion()) {
+        Some(name) => (
+            format!("{PAGE_CLASS} route-enter-{name}"),
+            format!("--route-transition-duration: {}", config.duration_value()),
+        ),
+        None => (PAGE_CLASS.to_string(), String::new()),
+    }
+}
+
+/// 为 `root` 中当前显示的页面播放离场动画，动画结束后返回
+///
+/// 首次加载时没有旧页面，直接返回。动画结束后移除离场 class 与时长变量，
+/// 跳转被取消、旧页面继续显示时不会停留在离场状态。
+pub async fn leave(config: TransitionConfig, root: Option<TransitionRoot>) {
+    let Some(name) = config.leave.name().filter(|_| !prefers_reduced_motion()) else {
+        return;
+    };
+    let Some(root) = root.and_then(|TransitionRoot(root)| root.get_untracked()) else {
+        return;
+    };
+    let Ok(nodes) = root.query_selector_all(&format!(".{PAGE_CLASS}")) else {
+        return;
+    };
+    let pages: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|index| nodes.get(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect();
+    if pages.is_empty() {
+        return;
+    }
+
+    let class = format!("route-leave-{name}");
+    for page in &pages {
+        let _ = page
+            .style()
+            .set_property("--route-transition-duration", &config.duration_value());
+        let _ = page.class_list().add_1(&class);
+    }
+    sleep(config.duration()).await;
+    for page in &pages {
+        let _ = page.class_list().remove_1(&class);
+        let _ = page.style().remove_property("--route-transition-duration");
+    }
+}
+
+async fn sleep(duration: Duration) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        let _ = window().set_timeout_with_callback_and_timeout_and_arguments_0(
+            &resolve,
+            duration.as_millis() as i32,
+        );
+    });
+    let _ = JsFuture::from(promise).await;
+}